@@ -0,0 +1,34 @@
+use throbberous::{Bar, Throbber};
+use tokio::time::{sleep, Duration};
+
+async fn copy_files(count: u64) -> Result<(), String> {
+    // Left where it stopped, marked as abandoned, if this returns early
+    let bar = Bar::new(count);
+    for i in 0..count {
+        if i == 7 {
+            return Err(format!("file {} is unreadable", i + 1));
+        }
+        bar.inc(1);
+        sleep(Duration::from_millis(100)).await;
+    }
+    bar.finish_with_message("Copied").await;
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    println!("Early Return:");
+
+    let throbber = Throbber::new();
+    throbber.set_message("Scanning...").await;
+    {
+        // Stops spinning when the guard goes out of scope
+        let _spinning = throbber.start().await;
+        sleep(Duration::from_secs(2)).await;
+    }
+
+    if let Err(err) = copy_files(10).await {
+        sleep(Duration::from_millis(50)).await;
+        println!("Error: {}", err);
+    }
+}
@@ -0,0 +1,30 @@
+use throbberous::{Bar, MultiProgress, Throbber};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    println!("Multiple Progress Bars:");
+
+    let multi = MultiProgress::new();
+    let throbber = multi.add(Throbber::new());
+    throbber.set_message("Resolving jobs...").await;
+    let spinning = throbber.start().await;
+
+    let mut jobs = Vec::new();
+    for (i, steps) in [30u64, 50, 80].into_iter().enumerate() {
+        let bar = multi.add(Bar::new(steps));
+        bar.set_message(format!("job {}", i + 1)).await;
+        jobs.push(tokio::spawn(async move {
+            for _ in 0..steps {
+                bar.inc(1);
+                sleep(Duration::from_millis(60)).await;
+            }
+        }));
+    }
+
+    for job in jobs {
+        let _ = job.await;
+    }
+    spinning.stop().await;
+    println!("Done!");
+}
@@ -7,15 +7,15 @@ async fn main() {
     // Throbber
     println!("\n1. Throbber");
     let throbber = Throbber::new();
-    throbber.start().await;
+    let spinning = throbber.start().await;
     sleep(Duration::from_secs(5)).await;
-    throbber.stop().await;
+    spinning.stop().await;
     
     // Determinate progress
     println!("\n2. Progress Bar:");
     let bar = Bar::new(50);
     for _i in 0..50 {
-        bar.inc(1).await;
+        bar.inc(1);
         sleep(Duration::from_millis(100)).await;
     }
     bar.finish().await;
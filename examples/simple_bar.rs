@@ -8,7 +8,7 @@ async fn main() {
     let bar = Bar::new(100);
     
     for _i in 0..100 {
-        bar.inc(1).await;
+        bar.inc(1);
         sleep(Duration::from_millis(50)).await;
         // Messages automatically change: "Working..." -> "Quarter done" -> "Halfway done" -> "Almost there..." -> "Complete!"
     }
@@ -6,8 +6,8 @@ async fn main() {
     println!("Throbber");
     
     let throbber = Throbber::new();
-    throbber.start().await;
+    let spinning = throbber.start().await;
     // Spins: | / - \ with "Working..."
     sleep(Duration::from_secs(5)).await;
-    throbber.stop().await;
+    spinning.stop().await;
 }
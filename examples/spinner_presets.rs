@@ -0,0 +1,15 @@
+use throbberous::{Spinner, Throbber, ThrobberConfig};
+use tokio::time::{sleep, Duration};
+
+#[tokio::main]
+async fn main() {
+    println!("Spinner Presets:");
+
+    for spinner in Spinner::ALL {
+        let throbber = Throbber::with_config(ThrobberConfig::preset(spinner));
+        throbber.set_message(format!("{:?}", spinner)).await;
+        let spinning = throbber.start().await;
+        sleep(Duration::from_secs(2)).await;
+        spinning.stop().await;
+    }
+}
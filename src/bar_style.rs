@@ -0,0 +1,121 @@
+//! How a bar is drawn: its fill style and the glyphs it is built from.
+
+/// Rendering style for the filled part of a [`Bar`](crate::Bar)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BarStyle {
+    /// Whole cells drawn with the configured [`BarGlyphs`], e.g. `[=====     ]`
+    #[default]
+    Classic,
+    /// Unicode eighth blocks that show fractional cells, e.g. `[████▌     ]`
+    Smooth,
+    /// ASCII-only fractional cells in quarter steps, e.g. `[####:     ]`
+    SmoothAscii,
+}
+
+/// Eighth blocks from one eighth up to seven eighths
+const EIGHTHS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// ASCII stand-ins for one, two and three quarters of a cell
+const QUARTERS: [char; 3] = ['.', ':', '='];
+
+/// The characters a bar is built from.
+///
+/// `filled` and `head` only apply to [`BarStyle::Classic`]; the smooth styles
+/// bring their own block characters but still use `empty` and the caps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BarGlyphs {
+    pub filled: char,
+    pub empty: char,
+    pub head: Option<char>, // drawn as the leading filled cell while incomplete
+    pub left: String,
+    pub right: String,
+}
+
+impl BarGlyphs {
+    /// `[=====     ]`
+    pub fn classic() -> Self {
+        Self::new('=', ' ', None, "[", "]")
+    }
+
+    /// `[====>     ]`
+    pub fn arrow() -> Self {
+        Self::new('=', ' ', Some('>'), "[", "]")
+    }
+
+    /// `[####>.....]`
+    pub fn hashes() -> Self {
+        Self::new('#', '.', Some('>'), "[", "]")
+    }
+
+    /// `━━━━╸─────`
+    pub fn line() -> Self {
+        Self::new('━', '─', Some('╸'), "", "")
+    }
+
+    /// `█████░░░░░`
+    pub fn blocks() -> Self {
+        Self::new('█', '░', None, "", "")
+    }
+
+    fn new(filled: char, empty: char, head: Option<char>, left: &str, right: &str) -> Self {
+        Self {
+            filled,
+            empty,
+            head,
+            left: left.to_string(),
+            right: right.to_string(),
+        }
+    }
+}
+
+impl Default for BarGlyphs {
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+impl BarStyle {
+    /// The character of a completely filled cell
+    pub(crate) fn full(self, glyphs: &BarGlyphs) -> char {
+        match self {
+            BarStyle::Classic => glyphs.filled,
+            BarStyle::Smooth => '█',
+            BarStyle::SmoothAscii => '#',
+        }
+    }
+
+    /// Characters for partially filled cells, from least to most filled
+    fn partials(self) -> &'static [char] {
+        match self {
+            BarStyle::Classic => &[],
+            BarStyle::Smooth => &EIGHTHS,
+            BarStyle::SmoothAscii => &QUARTERS,
+        }
+    }
+
+    /// Render `progress` (0.0 to 1.0) as exactly `width` cells, without caps
+    pub(crate) fn fill(self, progress: f64, width: usize, glyphs: &BarGlyphs) -> String {
+        let steps = self.partials().len() + 1;
+        let units = (progress.clamp(0.0, 1.0) * (width * steps) as f64).round() as usize;
+        let (mut full, partial) = (units / steps, units % steps);
+
+        // A head takes the place of the last filled cell until the bar is complete
+        let head = match (self, glyphs.head) {
+            (BarStyle::Classic, Some(head)) if full > 0 && full < width => {
+                full -= 1;
+                Some(head)
+            }
+            _ => None,
+        };
+
+        let mut bar = String::with_capacity(width * 3);
+        bar.extend(std::iter::repeat_n(self.full(glyphs), full));
+        bar.extend(head);
+        if partial > 0 {
+            bar.push(self.partials()[partial - 1]);
+        }
+        let used = full + usize::from(head.is_some()) + usize::from(partial > 0);
+        bar.extend(std::iter::repeat_n(glyphs.empty, width - used));
+        bar
+    }
+}
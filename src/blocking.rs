@@ -0,0 +1,278 @@
+//! A synchronous front-end for code that does not run inside an async runtime.
+//!
+//! These wrap the async [`Bar`](crate::Bar) and [`Throbber`](crate::Throbber)
+//! and render the same way, from background threads of their own. Every method
+//! returns once the update is made, and `finish`/`stop` wait until the final
+//! frame is on screen, so the program can exit right after.
+//!
+//! ```rust
+//! use throbberous::blocking::Bar;
+//!
+//! let bar = Bar::new(100);
+//! for _ in 0..100 {
+//!     bar.inc(1);
+//! }
+//! bar.finish_with_message("Done!");
+//! ```
+
+use std::time::Duration;
+
+use crate::{
+    rt::{block_on, on_threads},
+    BarConfig, DrawTarget, Drawable, Status, ThrobberConfig,
+};
+
+/// A synchronous progress bar, see [`crate::Bar`]
+#[derive(Clone)]
+pub struct Bar {
+    inner: crate::Bar,
+}
+
+impl Bar {
+    /// Creates a new determinate progress bar with a known total
+    pub fn new(total: u64) -> Self {
+        Self::with_config(total, BarConfig::default())
+    }
+
+    /// Creates a new determinate progress bar with custom configuration
+    pub fn with_config(total: u64, config: BarConfig) -> Self {
+        Self { inner: on_threads(|| crate::Bar::with_config(total, config)) }
+    }
+
+    /// Creates an indeterminate progress bar for unknown duration tasks
+    pub fn indeterminate(message: impl Into<String>) -> Self {
+        Self::indeterminate_with_config(message, BarConfig::default())
+    }
+
+    /// Creates an indeterminate progress bar with custom configuration
+    pub fn indeterminate_with_config(message: impl Into<String>, config: BarConfig) -> Self {
+        Self { inner: on_threads(|| crate::Bar::indeterminate_with_config(message, config)) }
+    }
+
+    /// Increment the progress bar by the specified amount (determinate mode only)
+    pub fn inc(&self, delta: u64) {
+        self.inner.inc(delta);
+    }
+
+    /// Set the current progress directly (determinate mode only)
+    pub fn set_position(&self, pos: u64) {
+        self.inner.set_position(pos);
+    }
+
+    /// Update the message displayed with the progress bar
+    pub fn set_message(&self, msg: impl Into<String>) {
+        block_on(self.inner.set_message(msg))
+    }
+
+    /// Update the prefix shown by the `{prefix}` template field
+    pub fn set_prefix(&self, prefix: impl Into<String>) {
+        block_on(self.inner.set_prefix(prefix))
+    }
+
+    /// Time since the bar was created
+    pub fn elapsed(&self) -> Duration {
+        block_on(self.inner.elapsed())
+    }
+
+    /// Recent throughput in items per second
+    pub fn rate(&self) -> f64 {
+        block_on(self.inner.rate())
+    }
+
+    /// Estimated time until the bar completes, see [`crate::Bar::eta`]
+    pub fn eta(&self) -> Option<Duration> {
+        block_on(self.inner.eta())
+    }
+
+    /// Finish the progress bar and wait until it is drawn
+    pub fn finish(&self) {
+        block_on(async {
+            self.inner.finish().await;
+            self.inner.drawn().await;
+        })
+    }
+
+    /// Finish the progress bar with a custom message and wait until it is drawn
+    pub fn finish_with_message(&self, msg: impl Into<String>) {
+        block_on(async {
+            self.inner.finish_with_message(msg).await;
+            self.inner.drawn().await;
+        })
+    }
+
+    /// Give up on the bar, leaving it marked as abandoned, and wait until it is drawn
+    pub fn abandon(&self) {
+        block_on(async {
+            self.inner.abandon().await;
+            self.inner.drawn().await;
+        })
+    }
+
+    /// End the bar marked as failed with `msg` and wait until it is drawn
+    pub fn fail(&self, msg: impl Into<String>) {
+        block_on(async {
+            self.inner.fail(msg).await;
+            self.inner.drawn().await;
+        })
+    }
+
+    /// End the bar marked as skipped with `msg` and wait until it is drawn
+    pub fn skip(&self, msg: impl Into<String>) {
+        block_on(async {
+            self.inner.skip(msg).await;
+            self.inner.drawn().await;
+        })
+    }
+
+    /// Whether and how the bar has ended
+    pub fn status(&self) -> Status {
+        self.inner.status()
+    }
+
+    /// Print a line above the bar without disturbing it
+    pub fn println(&self, msg: impl AsRef<str>) {
+        self.inner.println(msg)
+    }
+
+    /// Hide the bar while `f` runs, then draw it again, see [`DrawTarget::suspend`]
+    pub fn suspend<F: FnOnce() -> R, R>(&self, f: F) -> R {
+        self.inner.suspend(f)
+    }
+
+    /// The async bar behind this one
+    pub fn as_async(&self) -> &crate::Bar {
+        &self.inner
+    }
+}
+
+/// A synchronous spinner, see [`crate::Throbber`]
+#[derive(Clone)]
+pub struct Throbber {
+    inner: crate::Throbber,
+}
+
+impl Default for Throbber {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Throbber {
+    pub fn new() -> Self {
+        Self::with_config(ThrobberConfig::default())
+    }
+
+    pub fn with_config(config: ThrobberConfig) -> Self {
+        Self { inner: on_threads(|| crate::Throbber::with_config(config)) }
+    }
+
+    /// Start spinning until the returned guard is dropped or stopped
+    pub fn start(&self) -> ThrobberGuard {
+        block_on(self.inner.begin());
+        ThrobberGuard { throbber: Some(self.clone()) }
+    }
+
+    /// Stop the throbber and wait until it is cleared
+    pub fn stop(&self) {
+        block_on(async {
+            self.inner.stop().await;
+            self.inner.drawn().await;
+        })
+    }
+
+    /// Stop spinning, leaving the line marked as abandoned
+    pub fn abandon(&self) {
+        block_on(async {
+            self.inner.abandon().await;
+            self.inner.drawn().await;
+        })
+    }
+
+    /// Stop spinning and leave `msg` behind, marked as failed
+    pub fn fail(&self, msg: impl Into<String>) {
+        block_on(async {
+            self.inner.fail(msg).await;
+            self.inner.drawn().await;
+        })
+    }
+
+    /// Stop spinning and leave `msg` behind, marked as skipped
+    pub fn skip(&self, msg: impl Into<String>) {
+        block_on(async {
+            self.inner.skip(msg).await;
+            self.inner.drawn().await;
+        })
+    }
+
+    /// Whether and how the throbber has ended
+    pub fn status(&self) -> Status {
+        self.inner.status()
+    }
+
+    pub fn set_message(&self, msg: impl Into<String>) {
+        block_on(self.inner.set_message(msg))
+    }
+
+    /// Print a line above the throbber without disturbing it
+    pub fn println(&self, msg: impl AsRef<str>) {
+        self.inner.println(msg)
+    }
+
+    /// Hide the throbber while `f` runs, then draw it again, see [`DrawTarget::suspend`]
+    pub fn suspend<F: FnOnce() -> R, R>(&self, f: F) -> R {
+        self.inner.suspend(f)
+    }
+
+    /// The async throbber behind this one
+    pub fn as_async(&self) -> &crate::Throbber {
+        &self.inner
+    }
+}
+
+/// Keeps a synchronous throbber spinning until dropped, see [`crate::ThrobberGuard`]
+#[must_use = "the throbber stops as soon as the guard is dropped"]
+pub struct ThrobberGuard {
+    throbber: Option<Throbber>, // None once stopped
+}
+
+impl ThrobberGuard {
+    /// The throbber this guard keeps spinning
+    pub fn throbber(&self) -> &Throbber {
+        self.throbber.as_ref().expect("a live guard has a throbber")
+    }
+
+    /// Stop the throbber now rather than on drop, and wait until it is cleared
+    pub fn stop(mut self) {
+        if let Some(throbber) = self.throbber.take() {
+            throbber.stop();
+        }
+    }
+}
+
+impl Drop for ThrobberGuard {
+    fn drop(&mut self) {
+        if let Some(throbber) = self.throbber.take() {
+            throbber.stop();
+        }
+    }
+}
+
+impl Drawable for Bar {
+    fn draw_target(&self) -> DrawTarget {
+        self.inner.draw_target()
+    }
+
+    fn set_draw_target(&self, target: DrawTarget) {
+        self.inner.set_draw_target(target)
+    }
+}
+
+impl Drawable for Throbber {
+    fn draw_target(&self) -> DrawTarget {
+        self.inner.draw_target()
+    }
+
+    fn set_draw_target(&self, target: DrawTarget) {
+        self.inner.set_draw_target(target)
+    }
+}
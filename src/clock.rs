@@ -0,0 +1,115 @@
+//! The time source behind animations and estimates.
+
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::watch;
+
+/// Where bars and throbbers get the current time and how they wait.
+///
+/// The default real clock follows the wall clock. [`Clock::tokio`] follows
+/// tokio's clock, so it stands still while a test runtime is paused, and
+/// [`Clock::manual`] only moves when told to:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use throbberous::Clock;
+///
+/// let clock = Clock::manual();
+/// let start = clock.now();
+/// clock.advance(Duration::from_secs(90));
+/// assert_eq!(clock.now() - start, Duration::from_secs(90));
+/// ```
+#[derive(Clone, Default)]
+pub struct Clock {
+    kind: ClockKind,
+}
+
+#[derive(Clone, Default)]
+enum ClockKind {
+    #[default]
+    Real,
+    #[cfg(feature = "tokio")]
+    Tokio,
+    Manual(Arc<Manual>),
+}
+
+struct Manual {
+    start: Instant,
+    offset: watch::Sender<Duration>,
+}
+
+impl Clock {
+    /// The wall clock
+    pub fn real() -> Self {
+        Self { kind: ClockKind::Real }
+    }
+
+    /// tokio's clock, which can be paused and advanced with `tokio::time`
+    #[cfg(feature = "tokio")]
+    pub fn tokio() -> Self {
+        Self { kind: ClockKind::Tokio }
+    }
+
+    /// A clock that only moves on [`advance`](Self::advance)
+    pub fn manual() -> Self {
+        let manual = Manual {
+            start: Instant::now(),
+            offset: watch::Sender::new(Duration::ZERO),
+        };
+        Self { kind: ClockKind::Manual(Arc::new(manual)) }
+    }
+
+    /// The current time
+    pub fn now(&self) -> Instant {
+        match &self.kind {
+            ClockKind::Real => Instant::now(),
+            #[cfg(feature = "tokio")]
+            ClockKind::Tokio => tokio::time::Instant::now().into_std(),
+            ClockKind::Manual(manual) => manual.start + *manual.offset.borrow(),
+        }
+    }
+
+    /// Wait until `duration` has passed on this clock
+    pub async fn sleep(&self, duration: Duration) {
+        match &self.kind {
+            ClockKind::Real => crate::rt::sleep(duration).await,
+            #[cfg(feature = "tokio")]
+            ClockKind::Tokio => tokio::time::sleep(duration).await,
+            ClockKind::Manual(manual) => {
+                let mut offset = manual.offset.subscribe();
+                let until = *offset.borrow_and_update() + duration;
+                // The sender lives as long as `manual`, so this only ends by reaching `until`
+                while *offset.borrow_and_update() < until {
+                    let _ = offset.changed().await;
+                }
+            }
+        }
+    }
+
+    /// Move a manual clock forward by `duration`, waking anything sleeping past it.
+    ///
+    /// # Panics
+    ///
+    /// If this is not a [`manual`](Self::manual) clock.
+    pub fn advance(&self, duration: Duration) {
+        match &self.kind {
+            ClockKind::Manual(manual) => manual.offset.send_modify(|offset| *offset += duration),
+            _ => panic!("only a manual clock can be advanced"),
+        }
+    }
+}
+
+impl fmt::Debug for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match &self.kind {
+            ClockKind::Real => "Real",
+            #[cfg(feature = "tokio")]
+            ClockKind::Tokio => "Tokio",
+            ClockKind::Manual(_) => "Manual",
+        };
+        f.debug_tuple("Clock").field(&name).finish()
+    }
+}
@@ -0,0 +1,396 @@
+//! Draw targets decide where a [`Bar`](crate::Bar) or [`Throbber`](crate::Throbber)
+//! writes its output.
+
+use std::{
+    fmt,
+    io::{self, IsTerminal, Write},
+    sync::{Arc, Mutex, MutexGuard, OnceLock},
+    time::{Duration, Instant},
+};
+use crossterm::{
+    cursor::{MoveToColumn, MoveUp},
+    queue,
+    style::Color,
+    terminal::{self, Clear, ClearType},
+};
+
+use crate::{
+    line::{text_width, Line},
+    multi::MultiShared,
+};
+
+/// Where progress output is written.
+///
+/// The default target is stdout. Use [`DrawTarget::stderr`] when stdout carries
+/// piped data, [`DrawTarget::writer`] for files or in-memory buffers, and
+/// [`DrawTarget::hidden`] to suppress output entirely.
+///
+/// Targets that are not interactive terminals get plain, periodic lines instead
+/// of in-place redraws; see [`LineFallback`].
+#[derive(Clone)]
+pub struct DrawTarget {
+    kind: TargetKind,
+}
+
+#[derive(Clone)]
+enum TargetKind {
+    Surface(Arc<Mutex<Surface>>),
+    Multi { multi: Arc<MultiShared>, id: usize },
+    Hidden,
+}
+
+impl DrawTarget {
+    /// Draw to the process's standard output
+    pub fn stdout() -> Self {
+        static STDOUT: OnceLock<Arc<Mutex<Surface>>> = OnceLock::new();
+        let surface = STDOUT.get_or_init(|| Surface::shared(Output::Stdout, io::stdout().is_terminal(), None));
+        Self { kind: TargetKind::Surface(surface.clone()) }
+    }
+
+    /// Draw to the process's standard error
+    pub fn stderr() -> Self {
+        static STDERR: OnceLock<Arc<Mutex<Surface>>> = OnceLock::new();
+        let surface = STDERR.get_or_init(|| Surface::shared(Output::Stderr, io::stderr().is_terminal(), None));
+        Self { kind: TargetKind::Surface(surface.clone()) }
+    }
+
+    /// Draw to an arbitrary writer, such as a file or a `Vec<u8>`.
+    ///
+    /// The writer is treated as a non-terminal and receives plain lines.
+    pub fn writer<W: Write + Send + 'static>(writer: W) -> Self {
+        let surface = Surface::shared(Output::Writer(Box::new(writer)), false, None);
+        Self { kind: TargetKind::Surface(surface) }
+    }
+
+    /// Draw to a writer that is known to be an interactive terminal, such as a pty.
+    ///
+    /// The terminal's width is taken to be that of the controlling terminal, if any.
+    pub fn terminal<W: Write + Send + 'static>(writer: W) -> Self {
+        let surface = Surface::shared(Output::Writer(Box::new(writer)), true, None);
+        Self { kind: TargetKind::Surface(surface) }
+    }
+
+    /// Draw to a writer that is an interactive terminal exactly `columns` wide
+    pub fn terminal_with_width<W: Write + Send + 'static>(writer: W, columns: u16) -> Self {
+        let surface = Surface::shared(Output::Writer(Box::new(writer)), true, Some(columns));
+        Self { kind: TargetKind::Surface(surface) }
+    }
+
+    /// Draw nothing at all
+    pub fn hidden() -> Self {
+        Self { kind: TargetKind::Hidden }
+    }
+
+    /// Returns true if this target discards all output
+    pub fn is_hidden(&self) -> bool {
+        matches!(self.kind, TargetKind::Hidden)
+    }
+
+    /// Returns true if this target is an interactive terminal
+    pub fn is_terminal(&self) -> bool {
+        match &self.kind {
+            TargetKind::Surface(surface) => lock(surface).is_terminal,
+            TargetKind::Multi { multi, .. } => multi.target().is_terminal(),
+            TargetKind::Hidden => false,
+        }
+    }
+
+    /// The width of the terminal in columns, or `None` if this is not a terminal
+    pub fn width(&self) -> Option<usize> {
+        match &self.kind {
+            TargetKind::Surface(surface) => lock(surface).columns().map(usize::from),
+            TargetKind::Multi { multi, .. } => multi.target().width(),
+            TargetKind::Hidden => None,
+        }
+    }
+
+    pub(crate) fn multi(multi: Arc<MultiShared>, id: usize) -> Self {
+        Self { kind: TargetKind::Multi { multi, id } }
+    }
+
+    /// The row this target occupies in `owner`, if it belongs to it
+    pub(crate) fn multi_id(&self, owner: &Arc<MultiShared>) -> Option<usize> {
+        match &self.kind {
+            TargetKind::Multi { multi, id } if Arc::ptr_eq(multi, owner) => Some(*id),
+            _ => None,
+        }
+    }
+
+    /// Replace the current line with `line`, optionally colored
+    pub(crate) fn draw_line(&self, line: &Line, color: Option<Color>) {
+        let line = line.styled(color);
+        match &self.kind {
+            TargetKind::Surface(surface) => lock(surface).draw(vec![line]),
+            TargetKind::Multi { multi, id } => multi.set_line(*id, Some(line), false),
+            TargetKind::Hidden => {}
+        }
+    }
+
+    /// Replace the whole live region with `lines`
+    pub(crate) fn draw_lines(&self, lines: Vec<String>) {
+        match &self.kind {
+            TargetKind::Surface(surface) => lock(surface).draw(lines),
+            TargetKind::Multi { multi, id } => {
+                let line = (!lines.is_empty()).then(|| lines.join("\n"));
+                multi.set_line(*id, line, false)
+            }
+            TargetKind::Hidden => {}
+        }
+    }
+
+    /// Leave the last drawn line in place and move past it
+    pub(crate) fn finish_line(&self) {
+        match &self.kind {
+            TargetKind::Surface(surface) => lock(surface).commit(),
+            TargetKind::Multi { multi, id } => multi.finish(*id),
+            TargetKind::Hidden => {}
+        }
+    }
+
+    /// Erase the current line
+    pub(crate) fn clear_line(&self) {
+        match &self.kind {
+            TargetKind::Surface(surface) => lock(surface).clear(),
+            TargetKind::Multi { multi, id } => multi.set_line(*id, None, true),
+            TargetKind::Hidden => {}
+        }
+    }
+
+    /// Print `text` on its own line above whatever is currently drawn
+    pub fn println(&self, text: &str) {
+        match &self.kind {
+            TargetKind::Surface(surface) => lock(surface).println(text),
+            TargetKind::Multi { multi, .. } => multi.println(text),
+            TargetKind::Hidden => {}
+        }
+    }
+
+    /// Hide whatever is drawn on this target while `f` runs, then draw it again.
+    ///
+    /// Drawing to the same output waits until `f` returns, so `f` can write freely
+    /// but must not draw progress itself, e.g. by calling `println` on a bar.
+    pub fn suspend<F: FnOnce() -> R, R>(&self, f: F) -> R {
+        match &self.kind {
+            TargetKind::Surface(surface) => lock(surface).suspend(f),
+            TargetKind::Multi { multi, .. } => multi.suspend(f),
+            TargetKind::Hidden => f(),
+        }
+    }
+}
+
+impl Default for DrawTarget {
+    fn default() -> Self {
+        Self::stdout()
+    }
+}
+
+impl fmt::Debug for DrawTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match &self.kind {
+            TargetKind::Surface(surface) => match lock(surface).out {
+                Output::Stdout => "Stdout",
+                Output::Stderr => "Stderr",
+                Output::Writer(_) => "Writer",
+            },
+            TargetKind::Multi { .. } => "Multi",
+            TargetKind::Hidden => "Hidden",
+        };
+        f.debug_tuple("DrawTarget").field(&name).finish()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // A panic while drawing must not take every later draw down with it
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+enum Output {
+    Stdout,
+    Stderr,
+    Writer(Box<dyn Write + Send>),
+}
+
+/// A writer together with the lines currently drawn at its bottom
+struct Surface {
+    out: Output,
+    is_terminal: bool,
+    fixed_columns: Option<u16>,
+    region: Vec<String>,
+}
+
+impl Surface {
+    fn shared(out: Output, is_terminal: bool, fixed_columns: Option<u16>) -> Arc<Mutex<Self>> {
+        Arc::new(Mutex::new(Self { out, is_terminal, fixed_columns, region: Vec::new() }))
+    }
+
+    fn columns(&self) -> Option<u16> {
+        if !self.is_terminal {
+            return None;
+        }
+        self.fixed_columns
+            .or_else(|| terminal::size().ok().map(|(columns, _)| columns))
+            .filter(|&columns| columns > 0)
+    }
+
+    fn draw(&mut self, lines: Vec<String>) {
+        if lines == self.region {
+            return; // already on screen
+        }
+        let drawn = self.height(&self.region);
+        let _ = self.with_writer(|w| {
+            Self::erase(w, drawn)?;
+            for (i, line) in lines.iter().enumerate() {
+                if i > 0 {
+                    w.write_all(b"\n")?;
+                }
+                w.write_all(line.as_bytes())?;
+            }
+            w.flush()
+        });
+        self.region = lines;
+    }
+
+    fn commit(&mut self) {
+        if !self.region.is_empty() {
+            let _ = self.with_writer(|w| {
+                w.write_all(b"\n")?;
+                w.flush()
+            });
+            self.region.clear();
+        }
+    }
+
+    fn clear(&mut self) {
+        if self.region.is_empty() {
+            return;
+        }
+        let drawn = self.height(&self.region);
+        let _ = self.with_writer(|w| {
+            Self::erase(w, drawn)?;
+            w.flush()
+        });
+        self.region.clear();
+    }
+
+    fn println(&mut self, text: &str) {
+        let region = std::mem::take(&mut self.region);
+        if !region.is_empty() {
+            let drawn = self.height(&region);
+            let _ = self.with_writer(|w| Self::erase(w, drawn));
+        }
+        let _ = self.with_writer(|w| {
+            w.write_all(text.as_bytes())?;
+            w.write_all(b"\n")?;
+            w.flush()
+        });
+        if !region.is_empty() {
+            self.draw(region);
+        }
+    }
+
+    /// Erase the region, run `f`, then draw the region again below its output
+    fn suspend<F: FnOnce() -> R, R>(&mut self, f: F) -> R {
+        let region = std::mem::take(&mut self.region);
+        if !region.is_empty() {
+            let drawn = self.height(&region);
+            let _ = self.with_writer(|w| {
+                Self::erase(w, drawn)?;
+                w.flush()
+            });
+        }
+        let result = f();
+        if !region.is_empty() {
+            self.draw(region);
+        }
+        result
+    }
+
+    /// Move to the start of a region `drawn` rows tall and clear it
+    fn erase(w: &mut dyn Write, drawn: usize) -> io::Result<()> {
+        let mut w = w;
+        if drawn > 1 {
+            queue!(&mut w, MoveUp((drawn - 1) as u16))?;
+        }
+        queue!(&mut w, MoveToColumn(0), Clear(ClearType::FromCursorDown))
+    }
+
+    /// Rows taken up by `lines`, counting lines the terminal has wrapped
+    fn height(&self, lines: &[String]) -> usize {
+        let columns = self.columns().map_or(usize::MAX, usize::from);
+        lines
+            .iter()
+            .flat_map(|line| line.split('\n'))
+            .map(|row| text_width(row).div_ceil(columns).max(1))
+            .sum()
+    }
+
+    fn with_writer<F>(&mut self, f: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut dyn Write) -> io::Result<()>,
+    {
+        match &mut self.out {
+            Output::Stdout => f(&mut io::stdout().lock()),
+            Output::Stderr => f(&mut io::stderr().lock()),
+            Output::Writer(writer) => f(writer.as_mut()),
+        }
+    }
+}
+
+/// How a bar or throbber reports progress on a target that is not a terminal.
+///
+/// Instead of redrawing one line in place, a plain line without escape codes is
+/// printed at most every `interval` milliseconds, and additionally whenever a
+/// determinate bar crosses another `percent_step` percent.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineFallback {
+    pub interval: u64,
+    pub percent_step: u8, // 0 = only use the interval
+}
+
+impl Default for LineFallback {
+    fn default() -> Self {
+        Self {
+            interval: 5000,
+            percent_step: 10,
+        }
+    }
+}
+
+/// Per-draw-task bookkeeping for [`LineFallback`]
+pub(crate) struct FallbackState {
+    fallback: Option<LineFallback>,
+    last_at: Option<Instant>,
+    last_step: Option<u64>,
+}
+
+impl FallbackState {
+    pub(crate) fn new(fallback: Option<LineFallback>) -> Self {
+        Self { fallback, last_at: None, last_step: None }
+    }
+
+    /// Whether drawing to `target` should use plain lines
+    pub(crate) fn is_plain(&self, target: &DrawTarget) -> bool {
+        self.fallback.is_some() && !target.is_terminal()
+    }
+
+    /// Whether another plain line is due at `now`; `percent` is the bar's
+    /// progress, if it has one, and `force` overrides the throttle
+    pub(crate) fn is_due(&mut self, now: Instant, percent: Option<f64>, force: bool) -> bool {
+        let Some(fallback) = self.fallback else {
+            return true;
+        };
+        let step = percent
+            .filter(|_| fallback.percent_step > 0)
+            .map(|percent| percent as u64 / fallback.percent_step as u64);
+        let interval_passed = self
+            .last_at
+            .is_none_or(|last| now.saturating_duration_since(last) >= Duration::from_millis(fallback.interval));
+
+        let due = force || interval_passed || (step.is_some() && step != self.last_step);
+        if due {
+            self.last_at = Some(now);
+            self.last_step = step;
+        }
+        due
+    }
+}
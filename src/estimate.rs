@@ -0,0 +1,81 @@
+//! Elapsed time, throughput and time-remaining estimates for a bar.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+/// How many (time, position) samples the rate is averaged over
+const WINDOW: usize = 16;
+
+/// Samples closer together than this replace each other instead of piling up
+const MIN_SPACING: Duration = Duration::from_millis(50);
+
+pub(crate) struct Estimator {
+    started: Instant,
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl Estimator {
+    pub(crate) fn new(now: Instant) -> Self {
+        let mut samples = VecDeque::with_capacity(WINDOW);
+        samples.push_back((now, 0));
+        Self { started: now, samples }
+    }
+
+    /// Record that the bar was at `pos` at time `now`
+    pub(crate) fn record(&mut self, now: Instant, pos: u64) {
+        // A position going backwards invalidates everything learned so far
+        if self.samples.back().is_some_and(|&(_, last)| pos < last) {
+            self.samples.clear();
+        }
+        // Keep moving the newest sample forward until it is far enough from its predecessor
+        let len = self.samples.len();
+        let crowded = len > 1 && now.saturating_duration_since(self.samples[len - 2].0) < MIN_SPACING;
+        if crowded {
+            self.samples.pop_back();
+        } else if self.samples.len() == WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((now, pos));
+    }
+
+    pub(crate) fn elapsed(&self, now: Instant) -> Duration {
+        now.saturating_duration_since(self.started)
+    }
+
+    /// Items per second over the sample window, measured up to `now`
+    pub(crate) fn rate(&self, now: Instant) -> f64 {
+        let (Some(&(first_at, first)), Some(&(_, last))) = (self.samples.front(), self.samples.back()) else {
+            return 0.0;
+        };
+        let secs = now.saturating_duration_since(first_at).as_secs_f64();
+        if secs <= 0.0 {
+            return 0.0;
+        }
+        (last - first) as f64 / secs
+    }
+
+    /// Time left until `total` at the current rate, if there is any progress to go on
+    pub(crate) fn eta(&self, now: Instant, pos: u64, total: u64) -> Option<Duration> {
+        let rate = self.rate(now);
+        if pos >= total {
+            return Some(Duration::ZERO);
+        }
+        if rate <= 0.0 {
+            return None;
+        }
+        Some(Duration::from_secs_f64((total - pos) as f64 / rate))
+    }
+}
+
+/// Format a duration as `MM:SS`, or `H:MM:SS` once it reaches an hour
+pub(crate) fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
+    }
+}
@@ -0,0 +1,214 @@
+//! Readers and writers that count the bytes they move on a [`Bar`].
+//!
+//! Both wrappers work with std's `Read`/`Write` as well as tokio's or, with the
+//! `smol` or `async-std` feature, futures' `AsyncRead`/`AsyncWrite`, depending on
+//! what they wrap:
+//!
+//! ```rust,no_run
+//! use throbberous::ProgressReader;
+//! # #[cfg(feature = "tokio")]
+//! # tokio_test::block_on(async {
+//!
+//! let source = tokio::fs::File::open("archive.tar").await?;
+//! let mut reader = ProgressReader::file(source).await?;
+//! let mut dest = tokio::fs::File::create("copy.tar").await?;
+//! tokio::io::copy(&mut reader, &mut dest).await?;
+//! # Ok::<(), std::io::Error>(())
+//! # }).unwrap();
+//! ```
+
+use std::{
+    fs,
+    io::{self, Read, Write},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use pin_project_lite::pin_project;
+
+use crate::{Bar, BarConfig, Unit};
+
+/// Count `read` bytes; an empty read into a non-empty buffer means end of input
+fn record_read(bar: &Bar, read: usize, wanted: usize) {
+    if read > 0 {
+        bar.inc(read as u64);
+    } else if wanted > 0 {
+        bar.finish_now();
+    }
+}
+
+pin_project! {
+    /// Advances a [`Bar`] by every byte read, and finishes it at end of input
+    pub struct ProgressReader<R> {
+        #[pin]
+        inner: R,
+        bar: Bar,
+    }
+}
+
+impl<R> ProgressReader<R> {
+    /// Count the bytes read from `inner` on `bar`
+    pub fn new(inner: R, bar: Bar) -> Self {
+        Self { inner, bar }
+    }
+
+    /// The bar this reader drives
+    pub fn bar(&self) -> &Bar {
+        &self.bar
+    }
+
+    /// The wrapped reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Unwrap the reader; the bar keeps showing its last state
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ProgressReader<tokio::fs::File> {
+    /// Read `file` with a bar sized to the file's length, counting in [`Unit::Bytes`]
+    pub async fn file(file: tokio::fs::File) -> io::Result<Self> {
+        let len = file.metadata().await?.len();
+        Ok(Self::new(file, Bar::with_config(len, BarConfig::default().with_unit(Unit::Bytes))))
+    }
+}
+
+impl ProgressReader<fs::File> {
+    /// Read `file` with a bar sized to the file's length, counting in [`Unit::Bytes`]
+    pub fn std_file(file: fs::File) -> io::Result<Self> {
+        let len = file.metadata()?.len();
+        Ok(Self::new(file, Bar::with_config(len, BarConfig::default().with_unit(Unit::Bytes))))
+    }
+}
+
+impl<R: tokio::io::AsyncRead> tokio::io::AsyncRead for ProgressReader<R> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut tokio::io::ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let this = self.project();
+        let (before, wanted) = (buf.filled().len(), buf.remaining());
+        let result = this.inner.poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = result {
+            record_read(this.bar, buf.filled().len() - before, wanted);
+        }
+        result
+    }
+}
+
+impl<R: Read> Read for ProgressReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        record_read(&self.bar, read, buf.len());
+        Ok(read)
+    }
+}
+
+#[cfg(any(feature = "smol", feature = "async-std"))]
+impl<R: futures_io::AsyncRead> futures_io::AsyncRead for ProgressReader<R> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let result = this.inner.poll_read(cx, buf);
+        if let Poll::Ready(Ok(read)) = result {
+            record_read(this.bar, read, buf.len());
+        }
+        result
+    }
+}
+
+pin_project! {
+    /// Advances a [`Bar`] by every byte written.
+    ///
+    /// The destination's size is rarely known up front, so size the bar from the
+    /// source, e.g. `ProgressWriter::new(dest, Bar::new(source_len))`.
+    pub struct ProgressWriter<W> {
+        #[pin]
+        inner: W,
+        bar: Bar,
+    }
+}
+
+impl<W> ProgressWriter<W> {
+    /// Count the bytes written to `inner` on `bar`
+    pub fn new(inner: W, bar: Bar) -> Self {
+        Self { inner, bar }
+    }
+
+    /// The bar this writer drives
+    pub fn bar(&self) -> &Bar {
+        &self.bar
+    }
+
+    /// The wrapped writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Unwrap the writer; the bar keeps showing its last state
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: tokio::io::AsyncWrite> tokio::io::AsyncWrite for ProgressWriter<W> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let result = this.inner.poll_write(cx, buf);
+        if let Poll::Ready(Ok(written)) = result {
+            this.bar.inc(written as u64);
+        }
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    /// Shuts down the writer and finishes the bar
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.project();
+        let result = this.inner.poll_shutdown(cx);
+        if let Poll::Ready(Ok(())) = result {
+            this.bar.finish_now();
+        }
+        result
+    }
+}
+
+impl<W: Write> Write for ProgressWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.bar.inc(written as u64);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(any(feature = "smol", feature = "async-std"))]
+impl<W: futures_io::AsyncWrite> futures_io::AsyncWrite for ProgressWriter<W> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let result = this.inner.poll_write(cx, buf);
+        if let Poll::Ready(Ok(written)) = result {
+            this.bar.inc(written as u64);
+        }
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().inner.poll_flush(cx)
+    }
+
+    /// Closes the writer and finishes the bar
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.project();
+        let result = this.inner.poll_close(cx);
+        if let Poll::Ready(Ok(())) = result {
+            this.bar.finish_now();
+        }
+        result
+    }
+}
@@ -0,0 +1,129 @@
+//! Drive a [`Bar`] from an `Iterator` or a `Stream`.
+//!
+//! ```rust
+//! use throbberous::ProgressIterator;
+//! # tokio_test::block_on(async {
+//!
+//! let mut sum = 0;
+//! for n in (1..=100).progress() {
+//!     sum += n;
+//! }
+//! assert_eq!(sum, 5050);
+//! # });
+//! ```
+//!
+//! The bar's total comes from the `size_hint` when it is exact; otherwise the bar
+//! is indeterminate. Either way it is finished once the items run out.
+
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::Bar;
+
+/// A bar sized from `size_hint`, or an indeterminate one when the length is unknown
+fn bar_for(size_hint: (usize, Option<usize>)) -> Bar {
+    match size_hint {
+        (lower, Some(upper)) if lower == upper => Bar::new(upper as u64),
+        _ => Bar::indeterminate(""),
+    }
+}
+
+/// Adds [`progress`](ProgressIterator::progress) to every `Iterator`
+pub trait ProgressIterator: Iterator + Sized {
+    /// Show a new bar that advances with each item
+    fn progress(self) -> ProgressIter<Self> {
+        let bar = bar_for(self.size_hint());
+        self.progress_with(bar)
+    }
+
+    /// Advance `bar` with each item
+    fn progress_with(self, bar: Bar) -> ProgressIter<Self> {
+        ProgressIter { inner: self, bar, done: false }
+    }
+}
+
+impl<I: Iterator> ProgressIterator for I {}
+
+pin_project! {
+    /// An iterator or stream that advances a [`Bar`] as it yields items
+    pub struct ProgressIter<T> {
+        #[pin]
+        inner: T,
+        bar: Bar,
+        done: bool,
+    }
+}
+
+impl<T> ProgressIter<T> {
+    /// The bar this adapter drives, e.g. to change its message
+    pub fn bar(&self) -> &Bar {
+        &self.bar
+    }
+}
+
+impl<I: Iterator> Iterator for ProgressIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next();
+        match item {
+            Some(_) => {
+                self.bar.inc(1);
+            }
+            None if !self.done => {
+                self.done = true;
+                self.bar.finish_now();
+            }
+            None => {}
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+/// Adds [`progress`](ProgressStream::progress) to every `Stream`
+pub trait ProgressStream: Stream + Sized {
+    /// Show a new bar that advances with each item
+    fn progress(self) -> ProgressIter<Self> {
+        let bar = bar_for(self.size_hint());
+        self.progress_with(bar)
+    }
+
+    /// Advance `bar` with each item
+    fn progress_with(self, bar: Bar) -> ProgressIter<Self> {
+        ProgressIter { inner: self, bar, done: false }
+    }
+}
+
+impl<S: Stream> ProgressStream for S {}
+
+impl<S: Stream> Stream for ProgressIter<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let item = this.inner.poll_next(cx);
+        match &item {
+            Poll::Ready(Some(_)) => {
+                this.bar.inc(1);
+            }
+            Poll::Ready(None) if !*this.done => {
+                *this.done = true;
+                this.bar.finish_now();
+            }
+            _ => {}
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
@@ -0,0 +1,141 @@
+//! A `tracing` layer that shows a throbber for every span marked as progress.
+
+use std::{
+    fmt::{self, Write as _},
+    time::Instant,
+};
+use tracing_core::{
+    field::{Field, Visit},
+    span::{Attributes, Id},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+use crate::{rt, DrawTarget, MultiProgress, Throbber, ThrobberConfig};
+
+/// Shows a [`Throbber`] while a span with a `progress = true` field is open.
+///
+/// The throbber appears the first time the span is entered and shows the span's
+/// name and other fields. When the span closes it is replaced by a line with the
+/// elapsed time.
+///
+/// ```rust
+/// use throbberous::ProgressLayer;
+/// use tracing_subscriber::layer::SubscriberExt;
+///
+/// let subscriber = tracing_subscriber::registry().with(ProgressLayer::new());
+/// tracing::subscriber::with_default(subscriber, || {
+///     let _span = tracing::info_span!("fetch", progress = true, url = "example.com").entered();
+/// });
+/// ```
+pub struct ProgressLayer {
+    multi: MultiProgress,
+    config: ThrobberConfig,
+}
+
+/// Stored in the extensions of every span that shows progress
+struct SpanProgress {
+    label: String,
+    started: Instant,
+    throbber: Option<Throbber>,
+}
+
+impl ProgressLayer {
+    /// Draw throbbers to stdout with the default config
+    pub fn new() -> Self {
+        Self {
+            multi: MultiProgress::new(),
+            config: ThrobberConfig::default(),
+        }
+    }
+
+    /// Draw throbbers to `target`
+    pub fn with_target(mut self, target: DrawTarget) -> Self {
+        self.multi = MultiProgress::with_target(target);
+        self
+    }
+
+    /// Build every throbber from `config`; its target is replaced by the layer's
+    pub fn with_config(mut self, config: ThrobberConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
+
+impl Default for ProgressLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Layer<S> for ProgressLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut fields = SpanFields::default();
+        attrs.record(&mut fields);
+        if !fields.progress {
+            return;
+        }
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanProgress {
+                label: format!("{}{}", attrs.metadata().name(), fields.text),
+                started: self.config.clock.now(),
+                throbber: None,
+            });
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut extensions = span.extensions_mut();
+        let Some(progress) = extensions.get_mut::<SpanProgress>() else { return };
+        if progress.throbber.is_some() {
+            return;
+        }
+        let throbber = self.multi.add(Throbber::with_config(self.config.clone()));
+        let (starting, label) = (throbber.clone(), progress.label.clone());
+        rt::spawn(async move {
+            starting.set_message(label).await;
+            starting.begin().await;
+        });
+        progress.throbber = Some(throbber);
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else { return };
+        let Some(progress) = span.extensions_mut().remove::<SpanProgress>() else { return };
+        let Some(throbber) = progress.throbber else { return };
+
+        self.multi.remove(&throbber);
+        let elapsed = self.config.clock.now().saturating_duration_since(progress.started);
+        self.multi.println(format!("{} done in {:.2?}", progress.label, elapsed));
+        rt::spawn(async move { throbber.stop().await });
+    }
+}
+
+/// Collects `progress` and renders the other fields as ` key=value` pairs
+#[derive(Default)]
+struct SpanFields {
+    progress: bool,
+    text: String,
+}
+
+impl Visit for SpanFields {
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        if field.name() == "progress" {
+            self.progress = value;
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        let _ = write!(self.text, " {}={}", field.name(), value);
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        let _ = write!(self.text, " {}={:?}", field.name(), value);
+    }
+}
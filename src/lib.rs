@@ -12,7 +12,7 @@
 //!     // Regular progress bar
 //!     let bar = Bar::new(100);
 //!     for _i in 0..100 {
-//!         bar.inc(1).await;
+//!         bar.inc(1);
 //!         tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
 //!     }
 //!     bar.finish().await;
@@ -24,32 +24,164 @@
 //!
 //!     // Spinner
 //!     let throbber = Throbber::new();
-//!     throbber.start().await;
+//!     let spinning = throbber.start().await;
 //!     tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
-//!     throbber.stop().await;
+//!     spinning.stop().await;
 //! });
 //! ```
 
-use std::{io, sync::Arc, time::Duration};
-use crossterm::{
-    execute,
-    style::{Color, Print, SetForegroundColor, ResetColor},
-    terminal::{Clear, ClearType},
-    cursor::MoveToColumn,
-};
-use tokio::{
-    sync::{Mutex, Notify},
-    task::{self, JoinHandle},
-    time::sleep,
+mod bar_style;
+pub mod blocking;
+mod clock;
+mod draw_target;
+mod estimate;
+mod io;
+mod iter;
+#[cfg(feature = "tracing")]
+mod layer;
+mod line;
+#[cfg(any(feature = "log", feature = "tracing"))]
+mod logging;
+mod multi;
+mod rt;
+pub mod spinner;
+mod status;
+pub mod template;
+pub mod testing;
+mod units;
+
+pub use bar_style::{BarGlyphs, BarStyle};
+pub use clock::Clock;
+pub use draw_target::{DrawTarget, LineFallback};
+pub use io::{ProgressReader, ProgressWriter};
+pub use iter::{ProgressIter, ProgressIterator, ProgressStream};
+#[cfg(feature = "tracing")]
+pub use layer::ProgressLayer;
+#[cfg(feature = "log")]
+pub use logging::LogWrapper;
+#[cfg(feature = "tracing")]
+pub use logging::{EventWriter, TracingWriter};
+pub use multi::{Drawable, MultiProgress};
+pub use spinner::{Frames, Spinner};
+pub use status::{Mark, Marks, Status};
+pub use template::{Template, TemplateError};
+pub use units::Unit;
+
+use draw_target::FallbackState;
+use estimate::{format_duration, Estimator};
+use line::Line;
+use template::Key;
+
+use std::{
+    future::{self, IntoFuture, Ready},
+    pin::pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicU8, Ordering},
+        Arc, RwLock, Weak,
+    },
+    time::{Duration, Instant},
 };
+use crossterm::style::{Color, ContentStyle, Stylize};
+use tokio::sync::{watch, Mutex, Notify};
+
+use rt::Either;
+
+/// How often an idle bar checks whether the terminal was resized
+const RESIZE_POLL: Duration = Duration::from_millis(250);
+
+fn read_target(target: &RwLock<DrawTarget>) -> DrawTarget {
+    target.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+}
+
+fn read_status(status: &RwLock<Status>) -> Status {
+    *status.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The start of an ended line: its mark, colored if colors are on, and a space
+fn mark_line(mark: &Mark, colored: bool) -> Line {
+    let mut line = Line::default();
+    let style = mark.color.filter(|_| colored).map(|color| ContentStyle::new().with(color));
+    line.push(mark.symbol.as_str(), style);
+    line.push(" ", None);
+    line
+}
 
 // --- Progress Bar Implementation ---
 
+/// How many columns the bar itself takes up
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BarWidth {
+    /// Exactly this many columns, shrunk only if the terminal is too narrow
+    Fixed(usize),
+    /// Whatever the rest of the line leaves free on the terminal
+    Fill,
+    /// This fraction (0.0 to 1.0) of the terminal's width
+    Fraction(f32),
+}
+
+impl BarWidth {
+    /// Width used when the target is not a terminal and has no width of its own
+    const FALLBACK: usize = 40;
+
+    /// The bar never shrinks below this to make room; long messages get cut instead
+    const MIN: usize = 10;
+
+    /// Resolve to a column count, given the terminal width and the columns
+    /// already taken by the rest of the line
+    fn resolve(self, term_width: Option<usize>, rest: usize) -> usize {
+        let wanted = match (self, term_width) {
+            (BarWidth::Fixed(width), _) => width,
+            (BarWidth::Fill, Some(term)) => term,
+            (BarWidth::Fraction(fraction), Some(term)) => (term as f32 * fraction.clamp(0.0, 1.0)) as usize,
+            (BarWidth::Fill | BarWidth::Fraction(_), None) => Self::FALLBACK,
+        };
+        match term_width {
+            Some(term) => wanted.min(term.saturating_sub(rest)).max(wanted.min(Self::MIN)),
+            None => wanted,
+        }
+    }
+}
+
+impl Default for BarWidth {
+    fn default() -> Self {
+        BarWidth::Fixed(40)
+    }
+}
+
+impl From<usize> for BarWidth {
+    fn from(width: usize) -> Self {
+        BarWidth::Fixed(width)
+    }
+}
+
+/// What a bar does when its last handle is dropped before it finished, e.g. on
+/// an early return or a panic
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OnDrop {
+    /// Finish it at 100%, like `finish`
+    Finish,
+    /// Leave it where it stopped, marked as abandoned
+    #[default]
+    Abandon,
+    /// Erase it
+    Clear,
+}
+
 #[derive(Clone)]
 pub struct BarConfig {
     pub colors: Option<Vec<Color>>, // None = no colors
-    pub color_cycle_delay: u64,
-    pub width: usize,
+    pub color_cycle_delay: u64, // ms each color lasts
+    pub width: BarWidth,
+    pub style: BarStyle,
+    pub glyphs: BarGlyphs,
+    pub target: DrawTarget,
+    pub template: Option<Template>, // None = "{bar} {percent}% {msg}"
+    pub unit: Unit,
+    pub fallback: Option<LineFallback>, // None = always redraw in place
+    pub clock: Clock,
+    pub max_refresh_rate: Option<u32>, // redraws per second; None = redraw on every update
+    pub on_drop: OnDrop,
+    pub marks: Marks,
 }
 
 impl Default for BarConfig {
@@ -57,7 +189,17 @@ impl Default for BarConfig {
         Self {
             colors: Some(vec![Color::Green, Color::Yellow, Color::Magenta, Color::Cyan]),
             color_cycle_delay: 600,
-            width: 40,
+            width: BarWidth::default(),
+            style: BarStyle::default(),
+            glyphs: BarGlyphs::default(),
+            target: DrawTarget::stdout(),
+            template: None,
+            unit: Unit::default(),
+            fallback: Some(LineFallback::default()),
+            clock: Clock::default(),
+            max_refresh_rate: Some(20),
+            on_drop: OnDrop::default(),
+            marks: Marks::default(),
         }
     }
 }
@@ -67,8 +209,69 @@ impl BarConfig {
     pub fn no_colors() -> Self {
         Self {
             colors: None,
-            color_cycle_delay: 600,
-            width: 40,
+            ..Self::default()
+        }
+    }
+
+    /// Use a different draw target, e.g. `DrawTarget::stderr()`
+    pub fn with_target(mut self, target: DrawTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Take time from `clock`, e.g. `Clock::manual()` in tests
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Lay the line out with `template` instead of the default
+    pub fn with_template(mut self, template: Template) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Format positions, totals and rates in `unit`, e.g. `Unit::Bytes`
+    pub fn with_unit(mut self, unit: Unit) -> Self {
+        self.unit = unit;
+        self
+    }
+
+    /// Fill the bar using `style`, e.g. `BarStyle::Smooth`
+    pub fn with_style(mut self, style: BarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Build the bar from `glyphs`, e.g. `BarGlyphs::hashes()`
+    pub fn with_glyphs(mut self, glyphs: BarGlyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    /// Redraw at most `hz` times a second; updates in between are drawn together
+    pub fn with_max_refresh_rate(mut self, hz: u32) -> Self {
+        self.max_refresh_rate = Some(hz);
+        self
+    }
+
+    /// Decide what happens to the bar if it is dropped before it finished
+    pub fn with_on_drop(mut self, on_drop: OnDrop) -> Self {
+        self.on_drop = on_drop;
+        self
+    }
+
+    /// Mark ended bars with `marks` instead of the default symbols
+    pub fn with_marks(mut self, marks: Marks) -> Self {
+        self.marks = marks;
+        self
+    }
+
+    /// The shortest time between two redraws
+    fn refresh_interval(&self) -> Duration {
+        match self.max_refresh_rate {
+            Some(hz) if hz > 0 => Duration::from_secs(1) / hz,
+            _ => Duration::ZERO,
         }
     }
 }
@@ -76,21 +279,211 @@ impl BarConfig {
 #[derive(Clone, Copy)]
 pub enum BarMode {
     Determinate { current: u64, total: u64 },
-    Indeterminate { position: usize, direction: i8 }, // direction: 1 or -1
+    Indeterminate { tick: u64 }, // animation steps so far; the block bounces back and forth
 }
 
 struct BarState {
     mode: BarMode,
     finished: bool,
+    ending: Ending, // how it finished, once `finished`
     message: String,
-    color_index: usize,
+    prefix: String,
+    estimator: Estimator,
+    clock: Clock,
+    closed: bool, // the last line has been drawn
+}
+
+impl BarState {
+    /// Move a determinate bar to `pos`, keeping the automatic message and
+    /// finishing it once the total is reached
+    fn advance(&mut self, pos: u64) {
+        if self.finished {
+            return;
+        }
+        let BarMode::Determinate { current, total } = &mut self.mode else {
+            return;
+        };
+        *current = pos.min(*total);
+        let (current, total) = (*current, *total);
+        self.estimator.record(self.clock.now(), current);
+
+        if self.message.is_empty() {
+            self.message = match Bar::progress(current, total) {
+                p if p >= 1.0 => "Complete!".to_string(),
+                p if p >= 0.75 => "Almost there...".to_string(),
+                p if p >= 0.5 => "Halfway done".to_string(),
+                p if p >= 0.25 => "Quarter done".to_string(),
+                _ => "Working...".to_string(),
+            };
+        }
+        if current == total {
+            self.finished = true;
+        }
+    }
+
+    /// The color for the current cycle, moving on every `color_cycle_delay` ms
+    fn color(&self, config: &BarConfig) -> Option<Color> {
+        let colors = config.colors.as_ref()?;
+        let elapsed = self.estimator.elapsed(self.clock.now()).as_millis();
+        let index = (elapsed / u128::from(config.color_cycle_delay.max(1))) as usize;
+        Some(*colors.get(index % colors.len().max(1)).unwrap_or(&Color::White))
+    }
+
+    /// Catch up with the position, length and finish made outside the lock
+    fn sample(&mut self, position: &Position) {
+        if self.finished {
+            return;
+        }
+        if let BarMode::Determinate { current, ref mut total } = self.mode {
+            let len = position.len.load(Ordering::Acquire);
+            let changed = len != *total;
+            *total = len;
+            let pos = position.pos.load(Ordering::Acquire);
+            // A new total may put the bar at or past its end without it moving
+            if pos != current || changed {
+                self.advance(pos);
+            }
+        }
+        if self.finished {
+            // Reached the total, unless it already ended some other way
+            position.end(Ending::Finish);
+        }
+        if let Some(ending) = position.ending() {
+            if let (Ending::Finish, BarMode::Determinate { ref mut current, total }) = (ending, &mut self.mode) {
+                *current = *total;
+            }
+            self.ending = ending;
+            self.finished = true;
+        }
+    }
+}
+
+/// Where a determinate bar is, kept outside the state lock so updates never
+/// wait; sampled into [`BarState`] by the draw task and by [`Bar::lock`]
+#[derive(Default)]
+struct Position {
+    pos: AtomicU64,
+    len: AtomicU64,
+    ending: AtomicU8, // 0 = still running, otherwise an `Ending`
+    dirty: AtomicBool, // changed since the draw task last looked
+    determinate: bool,
+}
+
+impl Position {
+    fn new(len: u64) -> Self {
+        Self { len: AtomicU64::new(len), determinate: true, ..Self::default() }
+    }
+
+    /// Flag a change for the draw task, waking it only if it had seen everything so far
+    fn touch(&self, notify: &Notify) {
+        if !self.dirty.swap(true, Ordering::AcqRel) {
+            notify.notify_one();
+        }
+    }
+
+    /// End the bar, unless it has already ended some other way; true if this ended it
+    fn end(&self, ending: Ending) -> bool {
+        self.ending.compare_exchange(0, ending as u8, Ordering::AcqRel, Ordering::Acquire).is_ok()
+    }
+
+    fn ending(&self) -> Option<Ending> {
+        match self.ending.load(Ordering::Acquire) {
+            1 => Some(Ending::Finish),
+            2 => Some(Ending::Abandon),
+            3 => Some(Ending::Clear),
+            4 => Some(Ending::Fail),
+            5 => Some(Ending::Skip),
+            _ => None,
+        }
+    }
+}
+
+/// How a bar came to an end
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Ending {
+    Finish = 1,
+    Abandon = 2,
+    Clear = 3, // dropped with `OnDrop::Clear`
+    Fail = 4,
+    Skip = 5,
+}
+
+impl Ending {
+    fn status(self) -> Status {
+        match self {
+            Ending::Finish => Status::Finished,
+            Ending::Abandon | Ending::Clear => Status::Abandoned,
+            Ending::Fail => Status::Failed,
+            Ending::Skip => Status::Skipped,
+        }
+    }
+}
+
+impl From<OnDrop> for Ending {
+    fn from(on_drop: OnDrop) -> Self {
+        match on_drop {
+            OnDrop::Finish => Ending::Finish,
+            OnDrop::Abandon => Ending::Abandon,
+            OnDrop::Clear => Ending::Clear,
+        }
+    }
 }
 
+/// Returned by [`Bar::inc`] and [`Bar::set_position`], which take effect
+/// immediately. Awaiting it does nothing; it keeps code written for the former
+/// async versions compiling
+#[derive(Debug)]
+pub struct Updated(());
+
+impl IntoFuture for Updated {
+    type Output = ();
+    type IntoFuture = Ready<()>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        future::ready(())
+    }
+}
+
+/// A progress bar.
+///
+/// Clones are handles to the same bar, so many tasks can advance it at once. It
+/// finishes on an explicit `finish`, or ends as its [`OnDrop`] says once the last
+/// handle is dropped.
+#[derive(Clone)]
 pub struct Bar {
     inner: Arc<Mutex<BarState>>,
+    position: Arc<Position>,
+    notify: Arc<Notify>,
+    target: Arc<RwLock<DrawTarget>>,
+    drawn: watch::Receiver<()>, // closed once the draw task has drawn its last frame
+    _handle: Arc<LastHandle>,
+}
+
+/// Shared by every handle to a bar; ends the bar as configured when the last one goes
+struct LastHandle {
+    inner: Arc<Mutex<BarState>>,
+    position: Arc<Position>,
     notify: Arc<Notify>,
-    _draw_task: JoinHandle<()>,
-    _animate_task: Option<JoinHandle<()>>,
+    target: Arc<RwLock<DrawTarget>>,
+    config: BarConfig,
+}
+
+impl Drop for LastHandle {
+    fn drop(&mut self) {
+        // A bar that got all the way is finished however it was let go
+        let complete = self.position.determinate
+            && self.position.pos.load(Ordering::Acquire) >= self.position.len.load(Ordering::Acquire);
+        self.position.end(if complete { Ending::Finish } else { self.config.on_drop.into() });
+
+        // Draw the last line right here, as the runtime may be shutting down on an
+        // early return or a panic; if the draw task holds the state, it draws it
+        if let Ok(mut state) = self.inner.try_lock() {
+            state.sample(&self.position);
+            let mut fallback = FallbackState::new(self.config.fallback);
+            Bar::draw_end(&mut state, &self.config, &read_target(&self.target), &mut fallback);
+        }
+        self.notify.notify_one();
+    }
 }
 
 impl Bar {
@@ -109,20 +502,35 @@ impl Bar {
         let state = BarState {
             mode: BarMode::Determinate { current: 0, total },
             finished: false,
+            ending: Ending::Finish,
             message: String::new(),
-            color_index: 0,
+            prefix: String::new(),
+            estimator: Estimator::new(config.clock.now()),
+            clock: config.clock.clone(),
+            closed: false,
         };
 
         let inner = Arc::new(Mutex::new(state));
+        let position = Arc::new(Position::new(total));
         let notify = Arc::new(Notify::new());
+        let target = Arc::new(RwLock::new(config.target.clone()));
+        let handle = LastHandle {
+            inner: inner.clone(),
+            position: position.clone(),
+            notify: notify.clone(),
+            target: target.clone(),
+            config: config.clone(),
+        };
         
-        let draw_task = Self::spawn_draw_task(inner.clone(), notify.clone(), config);
+        let drawn = Self::spawn_draw_task(inner.clone(), position.clone(), notify.clone(), target.clone(), config);
 
         Bar { 
+            _handle: Arc::new(handle),
             inner, 
+            position,
             notify, 
-            _draw_task: draw_task,
-            _animate_task: None,
+            target,
+            drawn,
         }
     }
 
@@ -139,81 +547,121 @@ impl Bar {
     /// Creates an indeterminate progress bar with custom configuration
     pub fn indeterminate_with_config(message: impl Into<String>, config: BarConfig) -> Self {
         let state = BarState {
-            mode: BarMode::Indeterminate { position: 0, direction: 1 },
+            mode: BarMode::Indeterminate { tick: 0 },
             finished: false,
+            ending: Ending::Finish,
             message: message.into(),
-            color_index: 0,
+            prefix: String::new(),
+            estimator: Estimator::new(config.clock.now()),
+            clock: config.clock.clone(),
+            closed: false,
         };
 
         let inner = Arc::new(Mutex::new(state));
+        let position = Arc::new(Position::default());
         let notify = Arc::new(Notify::new());
+        let target = Arc::new(RwLock::new(config.target.clone()));
+        let handle = LastHandle {
+            inner: inner.clone(),
+            position: position.clone(),
+            notify: notify.clone(),
+            target: target.clone(),
+            config: config.clone(),
+        };
         
-        let draw_task = Self::spawn_draw_task(inner.clone(), notify.clone(), config.clone());
-        let animate_task = Self::spawn_indeterminate_task(inner.clone(), notify.clone(), config);
+        Self::spawn_indeterminate_task(inner.clone(), notify.clone(), config.clock.clone());
+        let drawn = Self::spawn_draw_task(inner.clone(), position.clone(), notify.clone(), target.clone(), config);
 
         Bar { 
+            _handle: Arc::new(handle),
             inner, 
+            position,
             notify, 
-            _draw_task: draw_task,
-            _animate_task: Some(animate_task),
+            target,
+            drawn,
         }
     }
 
     fn spawn_draw_task(
         inner: Arc<Mutex<BarState>>, 
+        position: Arc<Position>,
         notify: Arc<Notify>, 
+        target: Arc<RwLock<DrawTarget>>,
         config: BarConfig
-    ) -> JoinHandle<()> {
-        task::spawn(async move {
-            let mut stdout = io::stdout();
-            
+    ) -> watch::Receiver<()> {
+        let (drawn_tx, drawn) = watch::channel(());
+        rt::spawn(async move {
+            let _drawn = drawn_tx;
+            let mut fallback = FallbackState::new(config.fallback);
+            let mut drawn_width = read_target(&target).width();
+            let mut drawn_at = None;
+            let mut drawn_color = None;
+
             loop {
-                notify.notified().await;
+                let idle = rt::select(notify.notified(), config.clock.sleep(RESIZE_POLL)).await;
                 let mut state = inner.lock().await;
+                // Nothing changed, but a resized terminal needs a fresh layout and
+                // the colors move on by themselves
+                if matches!(idle, Either::Right(()))
+                    && read_target(&target).width() == drawn_width
+                    && state.color(&config) == drawn_color
+                {
+                    continue;
+                }
+                position.dirty.store(false, Ordering::Release);
+                state.sample(&position);
+
+                // Hold off until the refresh interval is up, taking in every update
+                // meanwhile, but draw the final state right away. Plain lines are
+                // paced by their fallback instead
+                let wait = drawn_at.map_or(Duration::ZERO, |at: Instant| {
+                    (at + config.refresh_interval()).saturating_duration_since(config.clock.now())
+                });
+                if !state.finished && !wait.is_zero() && !fallback.is_plain(&read_target(&target)) {
+                    drop(state);
+                    let mut interval = pin!(config.clock.sleep(wait));
+                    loop {
+                        let waited = rt::select(notify.notified(), interval.as_mut()).await;
+                        state = inner.lock().await;
+                        position.dirty.store(false, Ordering::Release);
+                        state.sample(&position);
+                        if state.finished || matches!(waited, Either::Right(())) {
+                            break;
+                        }
+                        drop(state);
+                    }
+                }
+                drawn_at = Some(config.clock.now());
+                let target = read_target(&target);
+                drawn_width = target.width();
                 
                 if state.finished {
-                    Self::draw_bar(&state, &config, &mut stdout);
-                    println!();
+                    Self::draw_end(&mut state, &config, &target, &mut fallback);
                     break;
                 }
 
-                Self::draw_bar(&state, &config, &mut stdout);
-                
-                // Only cycle colors if colors are enabled
-                if let Some(ref colors) = config.colors {
-                    if !colors.is_empty() {
-                        state.color_index = (state.color_index + 1) % colors.len();
-                    }
-                }
+                Self::draw_bar(&state, &config, &target, &mut fallback);
+                drawn_color = state.color(&config);
             }
-        })
+        });
+        drawn
     }
 
     fn spawn_indeterminate_task(
         inner: Arc<Mutex<BarState>>, 
         notify: Arc<Notify>, 
-        config: BarConfig
-    ) -> JoinHandle<()> {
-        task::spawn(async move {
-            let bounce_width = config.width / 4; // Size of the moving block
-            
+        clock: Clock,
+    ) {
+        rt::spawn(async move {
             loop {
-                sleep(Duration::from_millis(100)).await;
+                clock.sleep(Duration::from_millis(100)).await;
                 
                 let finished = {
                     let mut state = inner.lock().await;
                     if state.finished {
                         true
-                    } else if let BarMode::Indeterminate { ref mut position, ref mut direction } = state.mode {
-                        *position = (*position as i32 + *direction as i32) as usize;
-                        
-                        // Bounce off the edges
-                        if *position >= config.width - bounce_width {
-                            *direction = -1;
-                            *position = config.width - bounce_width;
-                        } else if *position == 0 {
-                            *direction = 1;
-                        }
+                    } else if let BarMode::Indeterminate { ref mut tick } = state.mode {
+                        *tick += 1;
                         false
                     } else {
                         true // Wrong mode, stop animating
@@ -229,159 +677,274 @@ impl Bar {
         })
     }
 
-    /// Increment the progress bar by the specified amount (determinate mode only)
-    pub async fn inc(&self, delta: u64) {
-        let mut state = self.inner.lock().await;
-        if !state.finished {
-            if let BarMode::Determinate { current, total } = &mut state.mode {
-                *current = (*current + delta).min(*total);
-                
-                // Check if we need to update message and if finished - extract values first
-                let progress = *current as f64 / *total as f64;
-                let current_val = *current;
-                let total_val = *total;
-                let message_empty = state.message.is_empty();
-                
-                // Now we can safely update state without conflicting borrows
-                if message_empty {
-                    state.message = match progress {
-                        p if p >= 1.0 => "Complete!".to_string(),
-                        p if p >= 0.75 => "Almost there...".to_string(),
-                        p if p >= 0.5 => "Halfway done".to_string(),
-                        p if p >= 0.25 => "Quarter done".to_string(),
-                        _ => "Working...".to_string(),
-                    };
-                }
-                
-                if current_val == total_val {
-                    state.finished = true;
-                }
-            }
-        }
-        drop(state);
-        self.notify.notify_one();
+    /// Increment the progress bar by the specified amount (determinate mode only).
+    ///
+    /// This never waits, so it is cheap enough for hot loops; the draw task picks
+    /// up the new position on its next redraw.
+    pub fn inc(&self, delta: u64) -> Updated {
+        self.position.pos.fetch_add(delta, Ordering::AcqRel);
+        self.position.touch(&self.notify);
+        Updated(())
     }
 
     /// Set the current progress directly (determinate mode only)
-    pub async fn set_position(&self, pos: u64) {
-        let mut state = self.inner.lock().await;
-        if !state.finished {
-            if let BarMode::Determinate { current, total } = &mut state.mode {
-                *current = pos.min(*total);
-                
-                // Check if we need to update message and if finished - extract values first
-                let progress = *current as f64 / *total as f64;
-                let current_val = *current;
-                let total_val = *total;
-                let message_empty = state.message.is_empty();
-                
-                // Now we can safely update state without conflicting borrows
-                if message_empty {
-                    state.message = match progress {
-                        p if p >= 1.0 => "Complete!".to_string(),
-                        p if p >= 0.75 => "Almost there...".to_string(),
-                        p if p >= 0.5 => "Halfway done".to_string(),
-                        p if p >= 0.25 => "Quarter done".to_string(),
-                        _ => "Working...".to_string(),
-                    };
-                }
-                
-                if current_val == total_val {
-                    state.finished = true;
-                }
-            }
+    pub fn set_position(&self, pos: u64) -> Updated {
+        self.position.pos.store(pos, Ordering::Release);
+        self.position.touch(&self.notify);
+        Updated(())
+    }
+
+    /// Change the total a determinate bar counts up to
+    pub fn set_length(&self, len: u64) {
+        self.position.len.store(len, Ordering::Release);
+        self.position.touch(&self.notify);
+    }
+
+    /// The current position, capped at the length; the length once finished
+    pub fn position(&self) -> u64 {
+        if self.position.ending() == Some(Ending::Finish) {
+            return self.length();
         }
-        drop(state);
-        self.notify.notify_one();
+        self.position.pos.load(Ordering::Acquire).min(self.length())
+    }
+
+    /// The total a determinate bar counts up to; 0 for indeterminate bars
+    pub fn length(&self) -> u64 {
+        self.position.len.load(Ordering::Acquire)
     }
 
     /// Update the message displayed with the progress bar
     pub async fn set_message(&self, msg: impl Into<String>) {
         {
-            let mut state = self.inner.lock().await;
+            let mut state = self.lock().await;
             state.message = msg.into();
         }
         self.notify.notify_one();
     }
 
-    /// Finish the progress bar
-    pub async fn finish(&self) {
+    /// Time since the bar was created
+    pub async fn elapsed(&self) -> Duration {
+        let state = self.lock().await;
+        state.estimator.elapsed(state.clock.now())
+    }
+
+    /// Recent throughput in items per second
+    pub async fn rate(&self) -> f64 {
+        let state = self.lock().await;
+        state.estimator.rate(state.clock.now())
+    }
+
+    /// Estimated time until the bar completes, or `None` while there is no
+    /// progress to extrapolate from (always `None` for indeterminate bars)
+    pub async fn eta(&self) -> Option<Duration> {
+        let state = self.lock().await;
+        match state.mode {
+            BarMode::Determinate { current, total } => state.estimator.eta(state.clock.now(), current, total),
+            BarMode::Indeterminate { .. } => None,
+        }
+    }
+
+    /// Update the prefix shown by the `{prefix}` template field
+    pub async fn set_prefix(&self, prefix: impl Into<String>) {
         {
-            let mut state = self.inner.lock().await;
-            // Set to 100% if determinate
-            if let BarMode::Determinate { ref mut current, total } = state.mode {
-                *current = total;
-            }
-            state.finished = true;
+            let mut state = self.lock().await;
+            state.prefix = prefix.into();
         }
         self.notify.notify_one();
     }
 
+    /// Finish the progress bar, setting it to 100% if determinate
+    pub async fn finish(&self) {
+        self.position.end(Ending::Finish);
+        drop(self.lock().await);
+        self.notify.notify_one();
+    }
+
     /// Finish the progress bar with a custom message
     pub async fn finish_with_message(&self, msg: impl Into<String>) {
-        {
-            let mut state = self.inner.lock().await;
-            // Set to 100% if determinate
-            if let BarMode::Determinate { ref mut current, total } = state.mode {
-                *current = total;
-            }
-            state.finished = true;
-            state.message = msg.into();
+        self.end_with_message(Ending::Finish, msg.into()).await;
+    }
+
+    /// Give up on the bar, leaving it where it stopped with the abandoned mark
+    pub async fn abandon(&self) {
+        self.position.end(Ending::Abandon);
+        drop(self.lock().await);
+        self.notify.notify_one();
+    }
+
+    /// End the bar where it stopped, marked as failed with `msg`
+    pub async fn fail(&self, msg: impl Into<String>) {
+        self.end_with_message(Ending::Fail, msg.into()).await;
+    }
+
+    /// End the bar where it stopped, marked as skipped with `msg`
+    pub async fn skip(&self, msg: impl Into<String>) {
+        self.end_with_message(Ending::Skip, msg.into()).await;
+    }
+
+    async fn end_with_message(&self, ending: Ending, msg: String) {
+        if self.position.end(ending) {
+            self.lock().await.message = msg;
+            self.notify.notify_one();
         }
+    }
+
+    /// Whether and how the bar has ended
+    pub fn status(&self) -> Status {
+        let complete = self.position.determinate && self.position.pos.load(Ordering::Acquire) >= self.length();
+        // Record a bar that reached its total, so a later `fail` or `abandon` can't undo it
+        if complete && self.position.end(Ending::Finish) {
+            self.notify.notify_one();
+        }
+        self.position.ending().map_or(Status::InProgress, Ending::status)
+    }
+
+    /// Lock the state, bringing it up to date with the latest position
+    async fn lock(&self) -> tokio::sync::MutexGuard<'_, BarState> {
+        let mut state = self.inner.lock().await;
+        state.sample(&self.position);
+        state
+    }
+
+    /// Finish without waiting for the state lock; the draw task applies it
+    pub(crate) fn finish_now(&self) {
+        self.position.end(Ending::Finish);
         self.notify.notify_one();
     }
 
-    fn draw_bar(state: &BarState, config: &BarConfig, stdout: &mut io::Stdout) {
-        let display = match state.mode {
-            BarMode::Determinate { current, total } => {
-                let progress = if total == 0 { 1.0 } else { (current as f64 / total as f64).min(1.0) };
-                let filled_len = (progress * config.width as f64).round() as usize;
-                let percent = (progress * 100.0).round();
-                
-                format!(
-                    "[{:=<filled$}{:width$}] {:.0}% {}", 
-                    "", 
-                    "", 
-                    percent, 
-                    state.message,
-                    filled = filled_len,
-                    width = config.width - filled_len
-                )
-            },
-            BarMode::Indeterminate { position, .. } => {
-                let bounce_width = config.width / 4;
-                let mut bar = vec![' '; config.width];
+    /// Wait until the draw task has drawn its last frame and exited
+    pub(crate) async fn drawn(&self) {
+        let mut drawn = self.drawn.clone();
+        while drawn.changed().await.is_ok() {}
+    }
+
+    /// The target this bar currently draws to
+    pub fn draw_target(&self) -> DrawTarget {
+        read_target(&self.target)
+    }
+
+    /// Redirect all further drawing to `target`
+    pub fn set_draw_target(&self, target: DrawTarget) {
+        *self.target.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = target;
+        self.notify.notify_one();
+    }
+
+    /// Print a line above the bar without disturbing it
+    pub fn println(&self, msg: impl AsRef<str>) {
+        self.draw_target().println(msg.as_ref());
+    }
+
+    /// Hide the bar while `f` runs, then draw it again, see [`DrawTarget::suspend`]
+    pub fn suspend<F: FnOnce() -> R, R>(&self, f: F) -> R {
+        self.draw_target().suspend(f)
+    }
+
+    fn progress(current: u64, total: u64) -> f64 {
+        if total == 0 { 1.0 } else { (current as f64 / total as f64).min(1.0) }
+    }
+
+    /// Render the bar's cells, `width` of them, between the glyphs' caps
+    fn render_bar(mode: BarMode, width: usize, style: BarStyle, glyphs: &BarGlyphs) -> String {
+        let cells = match mode {
+            BarMode::Determinate { current, total } => style.fill(Self::progress(current, total), width, glyphs),
+            BarMode::Indeterminate { tick } => {
+                let bounce_width = width / 4; // Size of the moving block
+                let mut bar = vec![glyphs.empty; width];
                 
+                // Walk to the right edge and back again
+                let travel = width.saturating_sub(bounce_width).max(1) as u64;
+                let step = tick % (2 * travel);
+                let position = if step <= travel { step } else { 2 * travel - step } as usize;
+                let position = position.min(width.saturating_sub(bounce_width));
+
                 // Fill the bouncing section
-                for i in position..=(position + bounce_width).min(config.width - 1) {
-                    if i < config.width {
-                        bar[i] = '=';
-                    }
+                let end = (position + bounce_width).min(width.saturating_sub(1));
+                for cell in bar.iter_mut().take(end + 1).skip(position) {
+                    *cell = style.full(glyphs);
                 }
                 
-                format!("[{}] {}", bar.iter().collect::<String>(), state.message)
+                bar.into_iter().collect()
             }
         };
+        format!("{}{}{}", glyphs.left, cells, glyphs.right)
+    }
+
+    /// Draw a finished bar's last line, unless that has been done already
+    fn draw_end(state: &mut BarState, config: &BarConfig, target: &DrawTarget, fallback: &mut FallbackState) {
+        if !state.finished || state.closed {
+            return;
+        }
+        state.closed = true;
+        match state.ending {
+            Ending::Finish | Ending::Abandon | Ending::Fail | Ending::Skip => {
+                Self::draw_bar(state, config, target, fallback);
+                target.finish_line();
+            }
+            Ending::Clear => target.clear_line(),
+        }
+    }
+
+    fn draw_bar(state: &BarState, config: &BarConfig, target: &DrawTarget, fallback: &mut FallbackState) {
+        if target.is_hidden() {
+            return;
+        }
+
+        let template = match &config.template {
+            Some(template) => template,
+            None => Template::default_for(matches!(state.mode, BarMode::Determinate { .. })),
+        };
+
+        let now = config.clock.now();
+        let value = |key, width: Option<usize>| match (key, state.mode) {
+            (Key::Bar, mode) => Self::render_bar(mode, width.unwrap_or(0), config.style, &config.glyphs),
+            (Key::Prefix, _) => state.prefix.clone(),
+            (Key::Msg, _) => state.message.clone(),
+            (Key::Pos, BarMode::Determinate { current, .. }) => config.unit.format(current),
+            (Key::Len, BarMode::Determinate { total, .. }) => config.unit.format(total),
+            (Key::Percent, BarMode::Determinate { current, total }) => {
+                format!("{:.0}", (Self::progress(current, total) * 100.0).round())
+            }
+            (Key::Elapsed, _) => format_duration(state.estimator.elapsed(now)),
+            (Key::Eta, BarMode::Determinate { current, total }) => match state.estimator.eta(now, current, total) {
+                Some(eta) => format_duration(eta),
+                None => "--:--".to_string(),
+            },
+            (Key::PerSec, BarMode::Determinate { .. }) => config.unit.format_rate(state.estimator.rate(now)),
+            (Key::Pos | Key::Len | Key::Percent | Key::Eta | Key::PerSec, BarMode::Indeterminate { .. }) => {
+                String::new()
+            }
+        };
+
+        // An ended bar starts with the mark for how it ended
+        let mut display = match state.finished.then(|| config.marks.get(state.ending.status())).flatten() {
+            Some(mark) => mark_line(mark, config.colors.is_some()),
+            None => Line::default(),
+        };
+
+        // Lay out everything but the bar first, then give the bar what fits
+        let term_width = target.width();
+        let rest = display.width() + template.render(&value).width();
+        let bar_width = config.width.resolve(term_width, rest);
+        display.append(template.render(|key, width| value(key, width.or(Some(bar_width)))));
+        if let Some(term_width) = term_width {
+            display.truncate(term_width);
+        }
 
         // Handle colors - if None, just print without colors
-        if let Some(ref colors) = config.colors {
-            let color = colors.get(state.color_index).unwrap_or(&Color::White);
-            let _ = execute!(
-                stdout,
-                MoveToColumn(0),
-                Clear(ClearType::CurrentLine),
-                SetForegroundColor(*color),
-                Print(&display),
-                ResetColor,
-            );
-        } else {
-            // No colors - just plain text
-            let _ = execute!(
-                stdout,
-                MoveToColumn(0),
-                Clear(ClearType::CurrentLine),
-                Print(&display),
-            );
+        let color = state.color(config);
+
+        if !fallback.is_plain(target) {
+            target.draw_line(&display, color);
+            return;
+        }
+
+        // Not a terminal: no colors or redraws, just an occasional plain line
+        let percent = match state.mode {
+            BarMode::Determinate { current, total } => Some(Self::progress(current, total) * 100.0),
+            BarMode::Indeterminate { .. } => None,
+        };
+        if fallback.is_due(now, percent, state.finished) {
+            target.println(display.plain().trim_end());
         }
     }
 }
@@ -390,20 +953,28 @@ impl Bar {
 
 #[derive(Clone)]
 pub struct ThrobberConfig {
-    pub frames: Vec<&'static str>,
+    pub frames: Frames,
     pub colors: Option<Vec<Color>>, // None = no colors
     pub frame_delay: u64,
+    pub target: DrawTarget,
+    pub fallback: Option<LineFallback>, // None = always redraw in place
+    pub clock: Clock,
+    pub marks: Marks,
 }
 
 impl Default for ThrobberConfig {
     fn default() -> Self {
         Self {
-            frames: vec!["|", "/", "-", "\\"],
+            frames: Spinner::Line.into(),
             colors: Some(vec![
                 Color::Green, Color::Yellow, Color::Magenta, Color::Cyan,
                 Color::Blue, Color::Red, Color::White, Color::DarkGrey,
             ]),
-            frame_delay: 150,
+            frame_delay: Spinner::Line.frame_delay(),
+            target: DrawTarget::stdout(),
+            fallback: Some(LineFallback::default()),
+            clock: Clock::default(),
+            marks: Marks::default(),
         }
     }
 }
@@ -412,24 +983,98 @@ impl ThrobberConfig {
     /// Create a config with no colors (plain text only)
     pub fn no_colors() -> Self {
         Self {
-            frames: vec!["|", "/", "-", "\\"],
             colors: None,
-            frame_delay: 150,
+            ..Self::default()
+        }
+    }
+
+    /// Use different frames, e.g. a `Vec<String>` or a `Frames::generator`
+    pub fn with_frames(mut self, frames: impl Into<Frames>) -> Self {
+        self.frames = frames.into();
+        self
+    }
+
+    /// Create a config using one of the built-in spinners and its recommended speed
+    pub fn preset(spinner: Spinner) -> Self {
+        Self {
+            frames: spinner.into(),
+            frame_delay: spinner.frame_delay(),
+            ..Self::default()
         }
     }
+
+    /// Use a different draw target, e.g. `DrawTarget::stderr()`
+    pub fn with_target(mut self, target: DrawTarget) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Take time from `clock`, e.g. `Clock::manual()` in tests
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Mark ended throbbers with `marks` instead of the default symbols
+    pub fn with_marks(mut self, marks: Marks) -> Self {
+        self.marks = marks;
+        self
+    }
 }
 
 struct ThrobberState {
-    frame_index: usize,
+    tick: u64,
     color_index: usize,
     running: bool,
     message: String,
+    last_message: Option<String>, // the message last drawn; None until the first frame
+    closed: bool, // the last line has been drawn
 }
 
+/// A spinner. Clones are handles to the same throbber
+#[derive(Clone)]
 pub struct Throbber {
     inner: Arc<Mutex<ThrobberState>>,
-    _draw_task: JoinHandle<()>,
-    _animate_task: JoinHandle<()>,
+    notify: Arc<Notify>,
+    target: Arc<RwLock<DrawTarget>>,
+    status: Arc<RwLock<Status>>, // kept outside the state lock so it can be read without waiting
+    config: Arc<ThrobberConfig>,
+    drawn: watch::Receiver<()>, // closed once the draw task has drawn its last frame
+}
+
+impl Default for Throbber {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Keeps a throbber spinning; it stops once this is dropped, e.g. on an early
+/// return or a panic. Returned by [`Throbber::start`]
+#[must_use = "the throbber stops as soon as the guard is dropped"]
+pub struct ThrobberGuard {
+    throbber: Option<Throbber>, // None once stopped
+}
+
+impl ThrobberGuard {
+    /// The throbber this guard keeps spinning
+    pub fn throbber(&self) -> &Throbber {
+        self.throbber.as_ref().expect("a live guard has a throbber")
+    }
+
+    /// Stop the throbber now rather than on drop
+    pub async fn stop(mut self) {
+        if let Some(throbber) = self.throbber.take() {
+            throbber.stop().await;
+        }
+    }
+}
+
+impl Drop for ThrobberGuard {
+    fn drop(&mut self) {
+        if let Some(throbber) = self.throbber.take() {
+            throbber.stop_now();
+        }
+    }
 }
 
 impl Throbber {
@@ -444,62 +1089,99 @@ impl Throbber {
 
     pub fn with_config(config: ThrobberConfig) -> Self {
         let state = ThrobberState {
-            frame_index: 0,
+            tick: 0,
             color_index: 0,
             running: false,
             message: "Throbbing...".to_string(),
+            last_message: None,
+            closed: false,
         };
 
         let inner = Arc::new(Mutex::new(state));
         let notify = Arc::new(Notify::new());
+        let target = Arc::new(RwLock::new(config.target.clone()));
+        let status = Arc::new(RwLock::new(Status::InProgress));
         
-        let draw_task = Self::spawn_draw_task(inner.clone(), notify.clone(), config.clone());
-        let animate_task = Self::spawn_animate_task(inner.clone(), notify, config);
+        let drawn = Self::spawn_draw_task(
+            Arc::downgrade(&inner),
+            notify.clone(),
+            target.clone(),
+            status.clone(),
+            config.clone(),
+        );
+        Self::spawn_animate_task(Arc::downgrade(&inner), notify.clone(), status.clone(), config.clone());
 
         Throbber {
             inner,
-            _draw_task: draw_task,
-            _animate_task: animate_task,
+            notify,
+            target,
+            status,
+            config: Arc::new(config),
+            drawn,
         }
     }
 
     fn spawn_draw_task(
-        inner: Arc<Mutex<ThrobberState>>, 
+        inner: Weak<Mutex<ThrobberState>>, 
         notify: Arc<Notify>, 
+        target: Arc<RwLock<DrawTarget>>,
+        status: Arc<RwLock<Status>>,
         config: ThrobberConfig
-    ) -> JoinHandle<()> {
-        task::spawn(async move {
-            let mut stdout = io::stdout();
-            
+    ) -> watch::Receiver<()> {
+        let (drawn_tx, drawn) = watch::channel(());
+        rt::spawn(async move {
+            let _drawn = drawn_tx;
+            let mut fallback = FallbackState::new(config.fallback);
+
             loop {
                 notify.notified().await;
-                let state = inner.lock().await;
+                // Every handle is gone, so nothing will change any more
+                let Some(inner) = inner.upgrade() else {
+                    break;
+                };
+                let mut state = inner.lock().await;
+                let target = read_target(&target);
                 
                 if !state.running {
-                    let _ = execute!(stdout, MoveToColumn(0), Clear(ClearType::CurrentLine));
+                    Self::draw_end(&mut state, read_status(&status), &config, &target, &mut fallback);
                     break;
                 }
                 
-                Self::draw_frame(&state, &config, &mut stdout);
+                // In plain mode a new message is worth a line of its own
+                let message_changed = state.last_message.as_ref() != Some(&state.message);
+                if message_changed {
+                    state.last_message = Some(state.message.clone());
+                }
+                Self::draw_frame(&state, &config, &target, &mut fallback, message_changed, None);
             }
-        })
+        });
+        drawn
     }
 
     fn spawn_animate_task(
-        inner: Arc<Mutex<ThrobberState>>, 
+        inner: Weak<Mutex<ThrobberState>>, 
         notify: Arc<Notify>, 
+        status: Arc<RwLock<Status>>,
         config: ThrobberConfig
-    ) -> JoinHandle<()> {
-        task::spawn(async move {
+    ) {
+        rt::spawn(async move {
             loop {
-                sleep(Duration::from_millis(config.frame_delay)).await;
+                config.clock.sleep(Duration::from_millis(config.frame_delay)).await;
                 
+                let Some(inner) = inner.upgrade() else {
+                    // Let the draw task see that every handle is gone too
+                    notify.notify_one();
+                    break;
+                };
                 let running = {
                     let mut state = inner.lock().await;
-                    if !state.running {
+                    if read_status(&status).is_done() {
                         false
+                    } else if !state.running {
+                        // Not started yet, so wait for `start`
+                        continue;
                     } else {
-                        state.frame_index = (state.frame_index + 1) % config.frames.len();
+                        state.tick += 1;
                         
                         // Only cycle colors if colors are enabled
                         if let Some(ref colors) = config.colors {
@@ -520,23 +1202,85 @@ impl Throbber {
         })
     }
 
-    pub async fn start(&self) {
+    /// Start spinning until the returned guard is dropped or stopped
+    pub async fn start(&self) -> ThrobberGuard {
+        self.begin().await;
+        ThrobberGuard { throbber: Some(self.clone()) }
+    }
+
+    /// Start spinning with no guard; it only stops on [`stop`](Self::stop)
+    pub(crate) async fn begin(&self) {
         {
             let mut state = self.inner.lock().await;
             if !state.running {
                 state.running = true;
-                state.frame_index = 0;
+                state.tick = 0;
                 state.color_index = 0;
             }
         }
     }
 
+    /// Stop spinning; stopping twice prints nothing the second time
     pub async fn stop(&self) {
+        self.end(Status::Finished, None).await;
+    }
+
+    /// Stop spinning and leave the line behind with the abandoned mark
+    pub async fn abandon(&self) {
+        self.end(Status::Abandoned, None).await;
+    }
+
+    /// Stop spinning and leave `msg` behind, marked as failed
+    pub async fn fail(&self, msg: impl Into<String>) {
+        self.end(Status::Failed, Some(msg.into())).await;
+    }
+
+    /// Stop spinning and leave `msg` behind, marked as skipped
+    pub async fn skip(&self, msg: impl Into<String>) {
+        self.end(Status::Skipped, Some(msg.into())).await;
+    }
+
+    /// Whether and how the throbber has ended
+    pub fn status(&self) -> Status {
+        read_status(&self.status)
+    }
+
+    /// Stop spinning without waiting, drawing the last line right away.
+    ///
+    /// A dropped guard may be the last thing to run before the runtime shuts down,
+    /// so this can't leave it to the draw task unless that holds the state.
+    fn stop_now(&self) {
+        {
+            let mut ended = self.status.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+            if !ended.is_done() {
+                *ended = Status::Finished;
+            }
+        }
+        let Ok(mut state) = self.inner.try_lock() else {
+            let throbber = self.clone();
+            return rt::spawn(async move { throbber.stop().await });
+        };
+        state.running = false;
+        let mut fallback = FallbackState::new(self.config.fallback);
+        Self::draw_end(&mut state, self.status(), &self.config, &self.draw_target(), &mut fallback);
+        drop(state);
+        self.notify.notify_one();
+    }
+
+    /// Stop spinning with `status`, unless it has already ended
+    async fn end(&self, status: Status, msg: Option<String>) {
         {
             let mut state = self.inner.lock().await;
+            let mut ended = self.status.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+            if !ended.is_done() {
+                *ended = status;
+                if let Some(msg) = msg {
+                    state.message = msg;
+                }
+            }
             state.running = false;
         }
-        println!("\nFinished");
+        self.notify.notify_one();
     }
 
     pub async fn set_message(&self, msg: impl Into<String>) {
@@ -546,29 +1290,89 @@ impl Throbber {
         }
     }
 
-    fn draw_frame(state: &ThrobberState, config: &ThrobberConfig, stdout: &mut io::Stdout) {
-        let frame = config.frames[state.frame_index];
-        let display = format!("{} {}", frame, state.message);
+    /// Wait until the draw task has drawn its last frame and exited
+    pub(crate) async fn drawn(&self) {
+        let mut drawn = self.drawn.clone();
+        while drawn.changed().await.is_ok() {}
+    }
+
+    /// The target this throbber currently draws to
+    pub fn draw_target(&self) -> DrawTarget {
+        read_target(&self.target)
+    }
+
+    /// Redirect all further drawing to `target`
+    pub fn set_draw_target(&self, target: DrawTarget) {
+        *self.target.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = target;
+    }
+
+    /// Print a line above the throbber without disturbing it
+    pub fn println(&self, msg: impl AsRef<str>) {
+        self.draw_target().println(msg.as_ref());
+    }
+
+    /// Hide the throbber while `f` runs, then draw it again, see [`DrawTarget::suspend`]
+    pub fn suspend<F: FnOnce() -> R, R>(&self, f: F) -> R {
+        self.draw_target().suspend(f)
+    }
+
+    /// Draw a stopped throbber's last line, unless that has been done already
+    fn draw_end(
+        state: &mut ThrobberState,
+        status: Status,
+        config: &ThrobberConfig,
+        target: &DrawTarget,
+        fallback: &mut FallbackState,
+    ) {
+        if state.closed {
+            return;
+        }
+        state.closed = true;
+        match config.marks.get(status) {
+            Some(mark) => {
+                Self::draw_frame(state, config, target, fallback, true, Some(mark));
+                target.finish_line();
+            }
+            None => {
+                target.clear_line();
+                if status == Status::Finished && state.last_message.is_some() {
+                    target.println("\nFinished");
+                }
+            }
+        }
+    }
+
+    fn draw_frame(
+        state: &ThrobberState,
+        config: &ThrobberConfig,
+        target: &DrawTarget,
+        fallback: &mut FallbackState,
+        message_changed: bool,
+        mark: Option<&Mark>, // drawn instead of the frame once ended
+    ) {
+        if target.is_hidden() {
+            return;
+        }
+
+        let mut display = match mark {
+            Some(mark) => mark_line(mark, config.colors.is_some()),
+            None => Line::from(format!("{} ", config.frames.frame(state.tick))),
+        };
+        display.append(Line::from(state.message.clone()));
+        if let Some(term_width) = target.width() {
+            display.truncate(term_width);
+        }
 
         // Handle colors - if None, just print without colors
-        if let Some(ref colors) = config.colors {
-            let color = colors.get(state.color_index).unwrap_or(&Color::White);
-            let _ = execute!(
-                stdout,
-                MoveToColumn(0),
-                Clear(ClearType::CurrentLine),
-                SetForegroundColor(*color),
-                Print(&display),
-                ResetColor,
-            );
-        } else {
-            // No colors - just plain text
-            let _ = execute!(
-                stdout,
-                MoveToColumn(0),
-                Clear(ClearType::CurrentLine),
-                Print(&display),
-            );
+        let color = config
+            .colors
+            .as_ref()
+            .map(|colors| *colors.get(state.color_index).unwrap_or(&Color::White));
+
+        if !fallback.is_plain(target) {
+            target.draw_line(&display, color);
+        } else if fallback.is_due(config.clock.now(), None, message_changed) {
+            target.println(&display.plain());
         }
     }
 }
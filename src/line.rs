@@ -0,0 +1,114 @@
+//! A rendered line of output, kept as styled spans until it is written.
+
+use crossterm::style::{Color, ContentStyle, Stylize};
+use unicode_width::UnicodeWidthChar;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Line {
+    spans: Vec<Span>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Span {
+    text: String,
+    style: Option<ContentStyle>,
+}
+
+impl Line {
+    pub(crate) fn push(&mut self, text: impl Into<String>, style: Option<ContentStyle>) {
+        let text = text.into();
+        match self.spans.last_mut() {
+            _ if text.is_empty() => {}
+            Some(last) if last.style == style => last.text.push_str(&text),
+            _ => self.spans.push(Span { text, style }),
+        }
+    }
+
+    /// Add every span of `other` to the end of this line
+    pub(crate) fn append(&mut self, other: Line) {
+        for span in other.spans {
+            self.push(span.text, span.style);
+        }
+    }
+
+    /// The number of terminal columns the line occupies
+    pub(crate) fn width(&self) -> usize {
+        self.spans.iter().map(|span| text_width(&span.text)).sum()
+    }
+
+    /// Cut the line down to at most `max` columns
+    pub(crate) fn truncate(&mut self, max: usize) {
+        let mut used = 0;
+        for (i, span) in self.spans.iter_mut().enumerate() {
+            let mut end = 0;
+            for (at, c) in span.text.char_indices() {
+                let w = c.width().unwrap_or(0);
+                if used + w > max {
+                    break;
+                }
+                used += w;
+                end = at + c.len_utf8();
+            }
+            if end < span.text.len() {
+                span.text.truncate(end);
+                self.spans.truncate(i + 1);
+                return;
+            }
+        }
+    }
+
+    /// The line without any escape codes
+    pub(crate) fn plain(&self) -> String {
+        self.spans.iter().map(|span| span.text.as_str()).collect()
+    }
+
+    /// The line with escape codes; unstyled spans take `base` as their color
+    pub(crate) fn styled(&self, base: Option<Color>) -> String {
+        let mut out = String::new();
+        for span in &self.spans {
+            let style = match (span.style, base) {
+                (Some(mut style), Some(base)) => {
+                    style.foreground_color = style.foreground_color.or(Some(base));
+                    Some(style)
+                }
+                (Some(style), None) => Some(style),
+                (None, Some(base)) => Some(ContentStyle::new().with(base)),
+                (None, None) => None,
+            };
+            match style {
+                Some(style) => out.push_str(&style.apply(span.text.as_str()).to_string()),
+                None => out.push_str(&span.text),
+            }
+        }
+        out
+    }
+}
+
+impl From<String> for Line {
+    fn from(text: String) -> Self {
+        let mut line = Line::default();
+        line.push(text, None);
+        line
+    }
+}
+
+/// The number of terminal columns `text` occupies, ignoring escape sequences
+pub(crate) fn text_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // CSI sequences run up to and including their final letter
+            if chars.next() == Some('[') {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+        } else {
+            width += c.width().unwrap_or(0);
+        }
+    }
+    width
+}
@@ -0,0 +1,133 @@
+//! Route `log` and `tracing` output above active bars and throbbers.
+//!
+//! Both adapters hide whatever is drawn on a [`DrawTarget`] while a record is
+//! written, then draw it again below, the same way [`DrawTarget::suspend`] does.
+//! Bars and throbbers drawing to stdout or stderr share one target per stream, so
+//! passing `DrawTarget::stdout()` covers every bar drawn there.
+
+use crate::DrawTarget;
+
+/// A `log::Log` that prints each record above the progress on a [`DrawTarget`].
+///
+/// ```rust,ignore
+/// let logger = env_logger::Builder::from_default_env().build();
+/// let level = logger.filter();
+/// LogWrapper::new(DrawTarget::stdout(), logger).try_init()?;
+/// log::set_max_level(level);
+/// ```
+#[cfg(feature = "log")]
+pub struct LogWrapper<L> {
+    target: DrawTarget,
+    logger: L,
+}
+
+#[cfg(feature = "log")]
+impl<L: log::Log + 'static> LogWrapper<L> {
+    /// Wrap `logger` so its records print above the progress on `target`
+    pub fn new(target: DrawTarget, logger: L) -> Self {
+        Self { target, logger }
+    }
+
+    /// Install as the global logger; the level still comes from `log::set_max_level`
+    pub fn try_init(self) -> Result<(), log::SetLoggerError> {
+        log::set_boxed_logger(Box::new(self))
+    }
+}
+
+#[cfg(feature = "log")]
+impl<L: log::Log> log::Log for LogWrapper<L> {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        self.logger.enabled(metadata)
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if self.logger.enabled(record.metadata()) {
+            self.target.suspend(|| self.logger.log(record));
+        }
+    }
+
+    fn flush(&self) {
+        self.logger.flush();
+    }
+}
+
+/// A `tracing_subscriber` writer that prints each event above the progress on
+/// a [`DrawTarget`].
+///
+/// ```rust
+/// use throbberous::{DrawTarget, TracingWriter};
+///
+/// let subscriber = tracing_subscriber::fmt()
+///     .with_writer(TracingWriter::stderr(DrawTarget::stderr()))
+///     .finish();
+/// # drop(subscriber);
+/// ```
+#[cfg(feature = "tracing")]
+#[derive(Clone)]
+pub struct TracingWriter<M> {
+    target: DrawTarget,
+    make_writer: M,
+}
+
+#[cfg(feature = "tracing")]
+impl TracingWriter<fn() -> std::io::Stderr> {
+    /// Write events to stderr
+    pub fn stderr(target: DrawTarget) -> Self {
+        Self::new(target, std::io::stderr)
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<M> TracingWriter<M> {
+    /// Write events through `make_writer`, e.g. a file or `std::io::stdout`
+    pub fn new(target: DrawTarget, make_writer: M) -> Self {
+        Self { target, make_writer }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<'a, M: tracing_subscriber::fmt::MakeWriter<'a>> tracing_subscriber::fmt::MakeWriter<'a> for TracingWriter<M> {
+    type Writer = EventWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        EventWriter {
+            target: self.target.clone(),
+            inner: self.make_writer.make_writer(),
+            buf: Vec::new(),
+        }
+    }
+}
+
+/// Collects one event and writes it out in one go once the event is complete
+#[cfg(feature = "tracing")]
+pub struct EventWriter<W: std::io::Write> {
+    target: DrawTarget,
+    inner: W,
+    buf: Vec<u8>,
+}
+
+#[cfg(feature = "tracing")]
+impl<W: std::io::Write> std::io::Write for EventWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<W: std::io::Write> Drop for EventWriter<W> {
+    fn drop(&mut self) {
+        if self.buf.is_empty() {
+            return;
+        }
+        let (inner, buf) = (&mut self.inner, &self.buf);
+        self.target.suspend(|| {
+            let _ = inner.write_all(buf);
+            let _ = inner.flush();
+        });
+    }
+}
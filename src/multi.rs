@@ -0,0 +1,194 @@
+//! Stacking several bars and throbbers on their own terminal rows.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::{Bar, DrawTarget, Throbber};
+
+/// Anything that can be placed inside a [`MultiProgress`]
+pub trait Drawable {
+    /// The target currently drawn to
+    fn draw_target(&self) -> DrawTarget;
+
+    /// Redirect all further drawing to `target`
+    fn set_draw_target(&self, target: DrawTarget);
+}
+
+impl Drawable for Bar {
+    fn draw_target(&self) -> DrawTarget {
+        Bar::draw_target(self)
+    }
+
+    fn set_draw_target(&self, target: DrawTarget) {
+        Bar::set_draw_target(self, target)
+    }
+}
+
+impl Drawable for Throbber {
+    fn draw_target(&self) -> DrawTarget {
+        Throbber::draw_target(self)
+    }
+
+    fn set_draw_target(&self, target: DrawTarget) {
+        Throbber::set_draw_target(self, target)
+    }
+}
+
+/// A group of bars and throbbers that share one region of the terminal.
+///
+/// Every member gets its own row, in insertion order, and the whole region is
+/// redrawn together whenever any member changes.
+///
+/// ```rust
+/// use throbberous::{Bar, MultiProgress, Throbber};
+/// use tokio_test::block_on;
+///
+/// block_on(async {
+///     let multi = MultiProgress::new();
+///     let download = multi.add(Bar::new(100));
+///     let unpack = multi.add(Throbber::new());
+///
+///     let unpacking = unpack.start().await;
+///     download.inc(100);
+///     unpacking.stop().await;
+/// });
+/// ```
+#[derive(Clone)]
+pub struct MultiProgress {
+    shared: Arc<MultiShared>,
+}
+
+pub(crate) struct MultiShared {
+    target: DrawTarget,
+    state: Mutex<MultiState>,
+}
+
+struct MultiState {
+    rows: Vec<Row>,
+    next_id: usize,
+}
+
+struct Row {
+    id: usize,
+    line: Option<String>,
+    done: bool,
+}
+
+impl MultiProgress {
+    /// Create an empty group drawing to stdout
+    pub fn new() -> Self {
+        Self::with_target(DrawTarget::stdout())
+    }
+
+    /// Create an empty group drawing to `target`
+    pub fn with_target(target: DrawTarget) -> Self {
+        let state = MultiState { rows: Vec::new(), next_id: 0 };
+        MultiProgress {
+            shared: Arc::new(MultiShared { target, state: Mutex::new(state) }),
+        }
+    }
+
+    /// Append `item` as the bottom row and return it
+    pub fn add<D: Drawable>(&self, item: D) -> D {
+        let len = self.shared.lock().rows.len();
+        self.insert(len, item)
+    }
+
+    /// Insert `item` at row `index` (clamped to the number of rows) and return it
+    pub fn insert<D: Drawable>(&self, index: usize, item: D) -> D {
+        let id = {
+            let mut state = self.shared.lock();
+            let id = state.next_id;
+            state.next_id += 1;
+            let index = index.min(state.rows.len());
+            state.rows.insert(index, Row { id, line: None, done: false });
+            id
+        };
+        item.set_draw_target(DrawTarget::multi(self.shared.clone(), id));
+        item
+    }
+
+    /// Remove `item` from the group; it will not be drawn anywhere afterwards
+    pub fn remove<D: Drawable>(&self, item: &D) {
+        let Some(id) = item.draw_target().multi_id(&self.shared) else {
+            return;
+        };
+        item.set_draw_target(DrawTarget::hidden());
+
+        let mut state = self.shared.lock();
+        state.rows.retain(|row| row.id != id);
+        self.shared.redraw(&mut state);
+    }
+
+    /// Print a line above the group without disturbing it
+    pub fn println(&self, msg: impl AsRef<str>) {
+        self.shared.println(msg.as_ref());
+    }
+
+    /// Hide the group while `f` runs, then draw it again, see [`DrawTarget::suspend`]
+    pub fn suspend<F: FnOnce() -> R, R>(&self, f: F) -> R {
+        self.shared.suspend(f)
+    }
+
+    /// Erase every row of the group from the terminal
+    pub fn clear(&self) {
+        let mut state = self.shared.lock();
+        for row in &mut state.rows {
+            row.line = None;
+        }
+        self.shared.target.clear_line();
+    }
+}
+
+impl Default for MultiProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MultiShared {
+    fn lock(&self) -> MutexGuard<'_, MultiState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn target(&self) -> &DrawTarget {
+        &self.target
+    }
+
+    pub(crate) fn set_line(&self, id: usize, line: Option<String>, done: bool) {
+        let mut state = self.lock();
+        if let Some(row) = state.rows.iter_mut().find(|row| row.id == id) {
+            row.line = line;
+            row.done = done;
+        }
+        self.redraw(&mut state);
+    }
+
+    pub(crate) fn finish(&self, id: usize) {
+        let mut state = self.lock();
+        if let Some(row) = state.rows.iter_mut().find(|row| row.id == id) {
+            row.done = true;
+        }
+        self.redraw(&mut state);
+    }
+
+    pub(crate) fn println(&self, text: &str) {
+        let _state = self.lock();
+        self.target.println(text);
+    }
+
+    pub(crate) fn suspend<F: FnOnce() -> R, R>(&self, f: F) -> R {
+        let _state = self.lock();
+        self.target.suspend(f)
+    }
+
+    fn redraw(&self, state: &mut MultiState) {
+        let lines = state.rows.iter().filter_map(|row| row.line.clone()).collect();
+        self.target.draw_lines(lines);
+
+        // Once every row is done, leave the region behind like a finished bar would
+        if !state.rows.is_empty() && state.rows.iter().all(|row| row.done) {
+            self.target.finish_line();
+            state.rows.clear();
+        }
+    }
+}
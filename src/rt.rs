@@ -0,0 +1,197 @@
+//! The executor that background drawing and animation tasks run on.
+//!
+//! Tasks go to the tokio runtime they were started from, when the `tokio` feature
+//! is on and there is one. Otherwise they go to smol's or async-std's global
+//! executor, if one of those features is on, and finally to a plain thread each.
+
+use std::{
+    cell::Cell,
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::Duration,
+};
+
+thread_local! {
+    static FORCE_THREADS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Run `f` with every task it spawns put on a thread of its own
+pub(crate) fn on_threads<R>(f: impl FnOnce() -> R) -> R {
+    let previous = FORCE_THREADS.replace(true);
+    let result = f();
+    FORCE_THREADS.set(previous);
+    result
+}
+
+/// Start `future` in the background; it keeps running on its own
+pub(crate) fn spawn<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    if FORCE_THREADS.get() {
+        return spawn_thread(future);
+    }
+    #[cfg(feature = "tokio")]
+    let future = match tokio::runtime::Handle::try_current() {
+        Ok(handle) => return drop(handle.spawn(future)),
+        Err(_) => future,
+    };
+    #[cfg(feature = "smol")]
+    return smol::spawn(future).detach();
+    #[cfg(all(feature = "async-std", not(feature = "smol")))]
+    return drop(async_std::task::spawn(future));
+    #[cfg(not(any(feature = "smol", feature = "async-std")))]
+    spawn_thread(future)
+}
+
+/// Wait for `duration` on whichever executor the caller runs on
+pub(crate) async fn sleep(duration: Duration) {
+    #[cfg(feature = "tokio")]
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return tokio::time::sleep(duration).await;
+    }
+    #[cfg(feature = "smol")]
+    smol::Timer::after(duration).await;
+    #[cfg(all(feature = "async-std", not(feature = "smol")))]
+    return async_std::task::sleep(duration).await;
+    #[cfg(not(any(feature = "smol", feature = "async-std")))]
+    timer::ThreadSleep { deadline: std::time::Instant::now() + duration }.await
+}
+
+/// Let other tasks run before continuing
+pub(crate) async fn yield_now() {
+    let mut yielded = false;
+    std::future::poll_fn(|cx| {
+        if yielded {
+            return Poll::Ready(());
+        }
+        yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    })
+    .await
+}
+
+/// Which of two futures finished first
+pub(crate) enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+/// Wait for whichever of `left` and `right` finishes first, preferring `left`
+pub(crate) async fn select<A: Future, B: Future>(left: A, right: B) -> Either<A::Output, B::Output> {
+    let (mut left, mut right) = (pin!(left), pin!(right));
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = left.as_mut().poll(cx) {
+            return Poll::Ready(Either::Left(output));
+        }
+        if let Poll::Ready(output) = right.as_mut().poll(cx) {
+            return Poll::Ready(Either::Right(output));
+        }
+        Poll::Pending
+    })
+    .await
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run `future` to completion on the calling thread
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+fn spawn_thread<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    thread::Builder::new()
+        .name("throbberous".to_string())
+        .spawn(move || block_on(future))
+        .expect("failed to spawn a throbberous thread");
+}
+
+/// Sleeping on the thread backend
+#[cfg(not(any(feature = "smol", feature = "async-std")))]
+mod timer {
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::{Condvar, Mutex, OnceLock},
+        task::{Context, Poll, Waker},
+        thread,
+        time::Instant,
+    };
+
+    /// A sleep for the thread backend, woken by the shared timer thread
+    pub(super) struct ThreadSleep {
+        pub(super) deadline: Instant,
+    }
+
+    impl Future for ThreadSleep {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if Instant::now() >= self.deadline {
+                return Poll::Ready(());
+            }
+            let timers = timers();
+            timers.pending.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push((self.deadline, cx.waker().clone()));
+            timers.changed.notify_one();
+            Poll::Pending
+        }
+    }
+
+    #[derive(Default)]
+    struct Timers {
+        pending: Mutex<Vec<(Instant, Waker)>>,
+        changed: Condvar,
+    }
+
+    /// The timers of the thread backend, served by one thread started on first use
+    fn timers() -> &'static Timers {
+        static TIMERS: OnceLock<&'static Timers> = OnceLock::new();
+        TIMERS.get_or_init(|| {
+            let timers: &'static Timers = Box::leak(Box::default());
+            thread::Builder::new()
+                .name("throbberous-timer".to_string())
+                .spawn(move || run_timers(timers))
+                .expect("failed to spawn the throbberous timer thread");
+            timers
+        })
+    }
+
+    fn run_timers(timers: &Timers) {
+        let mut pending = timers.pending.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        loop {
+            let now = Instant::now();
+            pending.retain(|(deadline, waker)| {
+                let due = *deadline <= now;
+                if due {
+                    waker.wake_by_ref();
+                }
+                !due
+            });
+            pending = match pending.iter().map(|(deadline, _)| *deadline).min() {
+                Some(next) => timers.changed.wait_timeout(pending, next - now).unwrap_or_else(|poisoned| poisoned.into_inner()).0,
+                None => timers.changed.wait(pending).unwrap_or_else(|poisoned| poisoned.into_inner()),
+            };
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! Built-in spinner animations for [`Throbber`](crate::Throbber).
+//!
+//! Use them with [`ThrobberConfig::preset`](crate::ThrobberConfig::preset):
+//!
+//! ```rust
+//! use throbberous::{Spinner, ThrobberConfig};
+//!
+//! let config = ThrobberConfig::preset(Spinner::Dots);
+//! assert_eq!(config.frame_delay, 80);
+//! ```
+//!
+//! | Preset         | Frames                   | Delay  | Width |
+//! |----------------|--------------------------|--------|-------|
+//! | `Line`         | `\| / - \`               | 150 ms | 1     |
+//! | `Dots`         | `⠋ ⠙ ⠹ ⠸ ⠼ ⠴ ⠦ ⠧ ⠇ ⠏`    | 80 ms  | 1     |
+//! | `DotsHeavy`    | `⣾ ⣽ ⣻ ⢿ ⡿ ⣟ ⣯ ⣷`        | 80 ms  | 1     |
+//! | `SimpleDots`   | `.  ` `.. ` `...` `   ` | 400 ms | 3     |
+//! | `Arc`          | `◜ ◠ ◝ ◞ ◡ ◟`            | 100 ms | 1     |
+//! | `CircleHalves` | `◐ ◓ ◑ ◒`                | 100 ms | 1     |
+//! | `Triangle`     | `◢ ◣ ◤ ◥`                | 100 ms | 1     |
+//! | `Arrows`       | `← ↖ ↑ ↗ → ↘ ↓ ↙`        | 100 ms | 1     |
+//! | `GrowVertical` | `▁ ▃ ▄ ▅ ▆ ▇ ▆ ▅ ▄ ▃`    | 120 ms | 1     |
+//! | `Star`         | `✶ ✸ ✹ ✺ ✹ ✷`            | 70 ms  | 1     |
+//! | `Toggle`       | `⊶ ⊷`                    | 250 ms | 1     |
+//! | `BouncingBar`  | `[=   ]` `[==  ]` ...    | 80 ms  | 6     |
+//! | `BouncingBall` | `( ●    )` ...           | 80 ms  | 8     |
+//! | `Clock`        | `🕛 🕐 🕑 ...`            | 100 ms | 2     |
+//! | `Moon`         | `🌑 🌒 🌓 🌔 ...`         | 80 ms  | 2     |
+//!
+//! The width is the number of terminal columns every frame of the preset takes up,
+//! which matters when lining spinners up with other output.
+
+use std::{borrow::Cow, fmt, sync::Arc};
+use unicode_width::UnicodeWidthStr;
+
+/// The frames a [`Throbber`](crate::Throbber) cycles through.
+///
+/// Either a fixed list, which converts from `Vec<&'static str>` and `Vec<String>`,
+/// or a generator that builds each frame from the animation tick:
+///
+/// ```rust
+/// use throbberous::{spinner::Frames, ThrobberConfig};
+///
+/// // A marquee scrolling the job name
+/// let name = "compiling throbberous ";
+/// let config = ThrobberConfig::default().with_frames(Frames::generator(move |tick| {
+///     let shift = tick as usize % name.len();
+///     format!("{}{}", &name[shift..], &name[..shift])
+/// }));
+/// assert_eq!(config.frames.frame(1), "ompiling throbberous c");
+/// ```
+#[derive(Clone)]
+pub enum Frames {
+    List(Vec<Cow<'static, str>>),
+    Generator(Arc<dyn Fn(u64) -> String + Send + Sync>),
+}
+
+impl Frames {
+    /// Build frames on the fly from the animation tick, which starts at 0
+    pub fn generator<F>(generate: F) -> Self
+    where
+        F: Fn(u64) -> String + Send + Sync + 'static,
+    {
+        Frames::Generator(Arc::new(generate))
+    }
+
+    /// The frame shown at animation step `tick`
+    pub fn frame(&self, tick: u64) -> Cow<'_, str> {
+        match self {
+            Frames::List(frames) if frames.is_empty() => Cow::Borrowed(""),
+            Frames::List(frames) => Cow::Borrowed(&frames[(tick % frames.len() as u64) as usize]),
+            Frames::Generator(generate) => Cow::Owned(generate(tick)),
+        }
+    }
+}
+
+impl From<Vec<&'static str>> for Frames {
+    fn from(frames: Vec<&'static str>) -> Self {
+        Frames::List(frames.into_iter().map(Cow::Borrowed).collect())
+    }
+}
+
+impl From<&'static [&'static str]> for Frames {
+    fn from(frames: &'static [&'static str]) -> Self {
+        frames.to_vec().into()
+    }
+}
+
+impl From<Vec<String>> for Frames {
+    fn from(frames: Vec<String>) -> Self {
+        Frames::List(frames.into_iter().map(Cow::Owned).collect())
+    }
+}
+
+impl From<Spinner> for Frames {
+    fn from(spinner: Spinner) -> Self {
+        spinner.frames().into()
+    }
+}
+
+impl fmt::Debug for Frames {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Frames::List(frames) => f.debug_tuple("List").field(frames).finish(),
+            Frames::Generator(_) => f.write_str("Generator(..)"),
+        }
+    }
+}
+
+/// A named spinner animation
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Spinner {
+    Line,
+    Dots,
+    DotsHeavy,
+    SimpleDots,
+    Arc,
+    CircleHalves,
+    Triangle,
+    Arrows,
+    GrowVertical,
+    Star,
+    Toggle,
+    BouncingBar,
+    BouncingBall,
+    Clock,
+    Moon,
+}
+
+impl Spinner {
+    /// Every preset, in the order listed in the module docs
+    pub const ALL: [Spinner; 15] = [
+        Spinner::Line,
+        Spinner::Dots,
+        Spinner::DotsHeavy,
+        Spinner::SimpleDots,
+        Spinner::Arc,
+        Spinner::CircleHalves,
+        Spinner::Triangle,
+        Spinner::Arrows,
+        Spinner::GrowVertical,
+        Spinner::Star,
+        Spinner::Toggle,
+        Spinner::BouncingBar,
+        Spinner::BouncingBall,
+        Spinner::Clock,
+        Spinner::Moon,
+    ];
+
+    /// The animation frames, shown in order and then repeated
+    pub fn frames(self) -> &'static [&'static str] {
+        match self {
+            Spinner::Line => &["|", "/", "-", "\\"],
+            Spinner::Dots => &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            Spinner::DotsHeavy => &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"],
+            Spinner::SimpleDots => &[".  ", ".. ", "...", "   "],
+            Spinner::Arc => &["◜", "◠", "◝", "◞", "◡", "◟"],
+            Spinner::CircleHalves => &["◐", "◓", "◑", "◒"],
+            Spinner::Triangle => &["◢", "◣", "◤", "◥"],
+            Spinner::Arrows => &["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"],
+            Spinner::GrowVertical => &["▁", "▃", "▄", "▅", "▆", "▇", "▆", "▅", "▄", "▃"],
+            Spinner::Star => &["✶", "✸", "✹", "✺", "✹", "✷"],
+            Spinner::Toggle => &["⊶", "⊷"],
+            Spinner::BouncingBar => &[
+                "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", "[   =]",
+                "[    ]", "[   =]", "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]",
+            ],
+            Spinner::BouncingBall => &[
+                "( ●    )", "(  ●   )", "(   ●  )", "(    ● )", "(     ●)",
+                "(    ● )", "(   ●  )", "(  ●   )", "( ●    )", "(●     )",
+            ],
+            Spinner::Clock => &[
+                "🕛", "🕐", "🕑", "🕒", "🕓", "🕔", "🕕", "🕖", "🕗", "🕘", "🕙", "🕚",
+            ],
+            Spinner::Moon => &["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"],
+        }
+    }
+
+    /// The recommended delay between frames, in milliseconds
+    pub fn frame_delay(self) -> u64 {
+        match self {
+            Spinner::Line => 150,
+            Spinner::Dots | Spinner::DotsHeavy | Spinner::BouncingBar | Spinner::BouncingBall | Spinner::Moon => 80,
+            Spinner::SimpleDots => 400,
+            Spinner::Arc | Spinner::CircleHalves | Spinner::Triangle | Spinner::Arrows | Spinner::Clock => 100,
+            Spinner::GrowVertical => 120,
+            Spinner::Star => 70,
+            Spinner::Toggle => 250,
+        }
+    }
+
+    /// The number of terminal columns the widest frame takes up
+    pub fn width(self) -> usize {
+        self.frames().iter().map(|frame| frame.width()).max().unwrap_or(0)
+    }
+}
@@ -0,0 +1,75 @@
+//! How a bar or throbber ended, and the mark it is left with on screen.
+
+use crossterm::style::Color;
+
+/// Where a [`Bar`](crate::Bar) or [`Throbber`](crate::Throbber) stands
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Status {
+    /// Not ended yet
+    #[default]
+    InProgress,
+    /// Finished or stopped normally
+    Finished,
+    /// Given up on, by `abandon` or by being dropped
+    Abandoned,
+    /// Ended by `fail`
+    Failed,
+    /// Ended by `skip`
+    Skipped,
+}
+
+impl Status {
+    /// Whether it has ended one way or another
+    pub fn is_done(self) -> bool {
+        self != Status::InProgress
+    }
+}
+
+/// A symbol drawn in front of an ended line, e.g. `✖` in red
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mark {
+    pub symbol: String,
+    pub color: Option<Color>, // None = the line's own color
+}
+
+impl Mark {
+    pub fn new(symbol: impl Into<String>, color: Option<Color>) -> Self {
+        Self { symbol: symbol.into(), color }
+    }
+}
+
+/// The [`Mark`] for each way a line can end; `None` leaves the line unmarked.
+///
+/// Finished lines are unmarked by default; use e.g.
+/// `Some(Mark::new("✔", Some(Color::Green)))` to tick them off.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Marks {
+    pub finished: Option<Mark>,
+    pub abandoned: Option<Mark>,
+    pub failed: Option<Mark>,
+    pub skipped: Option<Mark>,
+}
+
+impl Marks {
+    /// The mark for a line that ended with `status`
+    pub fn get(&self, status: Status) -> Option<&Mark> {
+        match status {
+            Status::InProgress => None,
+            Status::Finished => self.finished.as_ref(),
+            Status::Abandoned => self.abandoned.as_ref(),
+            Status::Failed => self.failed.as_ref(),
+            Status::Skipped => self.skipped.as_ref(),
+        }
+    }
+}
+
+impl Default for Marks {
+    fn default() -> Self {
+        Self {
+            finished: None,
+            abandoned: Some(Mark::new("⊘", Some(Color::Yellow))),
+            failed: Some(Mark::new("✖", Some(Color::Red))),
+            skipped: Some(Mark::new("↷", Some(Color::DarkGrey))),
+        }
+    }
+}
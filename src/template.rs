@@ -0,0 +1,297 @@
+//! Template strings that describe how a [`Bar`](crate::Bar) line is laid out.
+//!
+//! A template is plain text with fields in braces:
+//!
+//! ```text
+//! {prefix} {bar:30} {pos}/{len} {percent}% {msg}
+//! ```
+//!
+//! Each field may carry a spec after a colon: an optional alignment (`<`, `^`
+//! or `>`), an optional width, and any number of `.style` annotations, e.g.
+//! `{msg:<20.bold.cyan}` or `{prefix:.dim}`. Use `{{` and `}}` for literal braces.
+//!
+//! Available fields:
+//!
+//! | Field       | Content                                                |
+//! |-------------|--------------------------------------------------------|
+//! | `{bar}`     | the bar and its caps; a width sets the number of cells |
+//! | `{prefix}`  | the text set with `Bar::set_prefix`                    |
+//! | `{msg}`     | the text set with `Bar::set_message`                   |
+//! | `{pos}`     | the current position, in the bar's unit                |
+//! | `{len}`     | the total, in the bar's unit                           |
+//! | `{percent}` | the completed percentage, without a `%`                |
+//! | `{elapsed}` | time since the bar was created                         |
+//! | `{eta}`     | estimated time remaining                               |
+//! | `{per_sec}` | recent throughput, e.g. `12.5/s` or `48.0 MiB/s`       |
+//!
+//! See [`Unit`](crate::Unit) for how amounts are formatted.
+//!
+//! Styles are color names (`black`, `red`, `green`, `yellow`, `blue`, `magenta`,
+//! `cyan`, `white`, `grey`, their `dark_` variants, and `on_<color>` for the
+//! background) and the attributes `bold`, `dim`, `italic`, `underline` and `reverse`.
+
+use std::{error::Error, fmt, sync::OnceLock};
+use crossterm::style::{Attribute, Color, ContentStyle};
+
+use crate::line::{text_width, Line};
+
+/// A parsed line template for [`BarConfig`](crate::BarConfig)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Template {
+    parts: Vec<Part>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Part {
+    Literal(String),
+    Field(Field),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Field {
+    key: Key,
+    align: Align,
+    width: Option<usize>,
+    style: Option<ContentStyle>,
+}
+
+/// The value a template field refers to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Key {
+    Bar,
+    Prefix,
+    Msg,
+    Pos,
+    Len,
+    Percent,
+    Elapsed,
+    Eta,
+    PerSec,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// Why a template string could not be parsed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TemplateError {
+    /// A `{` was never closed; holds its byte offset
+    UnclosedField(usize),
+    /// A `}` appeared outside a field; holds its byte offset
+    UnmatchedBrace(usize),
+    /// The field name is not one of the known fields
+    UnknownField(String),
+    /// The width in a field spec is not a number
+    InvalidWidth(String),
+    /// A `.style` annotation is not a known color or attribute
+    UnknownStyle(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnclosedField(at) => write!(f, "unclosed `{{` at byte {}", at),
+            Self::UnmatchedBrace(at) => write!(f, "unmatched `}}` at byte {}", at),
+            Self::UnknownField(name) => write!(f, "unknown template field `{}`", name),
+            Self::InvalidWidth(width) => write!(f, "invalid field width `{}`", width),
+            Self::UnknownStyle(style) => write!(f, "unknown style `{}`", style),
+        }
+    }
+}
+
+impl Error for TemplateError {}
+
+impl Template {
+    /// Parse a template string, see the [module docs](self) for the syntax
+    pub fn parse(template: &str) -> Result<Self, TemplateError> {
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut chars = template.char_indices().peekable();
+
+        while let Some((at, c)) = chars.next() {
+            match c {
+                '{' if matches!(chars.peek(), Some((_, '{'))) => {
+                    chars.next();
+                    literal.push('{');
+                }
+                '}' if matches!(chars.peek(), Some((_, '}'))) => {
+                    chars.next();
+                    literal.push('}');
+                }
+                '}' => return Err(TemplateError::UnmatchedBrace(at)),
+                '{' => {
+                    let rest = &template[at + 1..];
+                    let len = rest.find('}').ok_or(TemplateError::UnclosedField(at))?;
+                    if !literal.is_empty() {
+                        parts.push(Part::Literal(std::mem::take(&mut literal)));
+                    }
+                    parts.push(Part::Field(Field::parse(&rest[..len])?));
+                    // Skip past the field body and its closing brace
+                    while chars.next_if(|&(i, _)| i <= at + 1 + len).is_some() {}
+                }
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(literal));
+        }
+
+        Ok(Template { parts })
+    }
+
+    /// The default layout of a determinate bar: `{bar} {percent}% {msg}`
+    pub fn determinate() -> Self {
+        Self::parse("{bar} {percent}% {msg}").expect("built-in template is valid")
+    }
+
+    /// The default layout of an indeterminate bar: `{bar} {msg}`
+    pub fn indeterminate() -> Self {
+        Self::parse("{bar} {msg}").expect("built-in template is valid")
+    }
+
+    /// The default layout for a bar, parsed once and shared by every redraw
+    pub(crate) fn default_for(determinate: bool) -> &'static Template {
+        static DETERMINATE: OnceLock<Template> = OnceLock::new();
+        static INDETERMINATE: OnceLock<Template> = OnceLock::new();
+        if determinate {
+            DETERMINATE.get_or_init(Self::determinate)
+        } else {
+            INDETERMINATE.get_or_init(Self::indeterminate)
+        }
+    }
+
+    /// Render the template, asking `value` for the text of each field.
+    ///
+    /// `value` receives the field's explicit width, which only matters for `{bar}`.
+    pub(crate) fn render<F>(&self, mut value: F) -> Line
+    where
+        F: FnMut(Key, Option<usize>) -> String,
+    {
+        let mut line = Line::default();
+        for part in &self.parts {
+            match part {
+                Part::Literal(text) => line.push(text.as_str(), None),
+                Part::Field(field) => {
+                    let text = value(field.key, field.width);
+                    line.push(field.pad(text), field.style);
+                }
+            }
+        }
+        line
+    }
+}
+
+impl std::str::FromStr for Template {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl Field {
+    fn parse(body: &str) -> Result<Self, TemplateError> {
+        let (name, spec) = body.split_once(':').unwrap_or((body, ""));
+        let key = match name.trim() {
+            "bar" => Key::Bar,
+            "prefix" => Key::Prefix,
+            "msg" => Key::Msg,
+            "pos" => Key::Pos,
+            "len" => Key::Len,
+            "percent" => Key::Percent,
+            "elapsed" => Key::Elapsed,
+            "eta" => Key::Eta,
+            "per_sec" => Key::PerSec,
+            other => return Err(TemplateError::UnknownField(other.to_string())),
+        };
+
+        let mut styles = spec.split('.');
+        let mut layout = styles.next().unwrap_or("");
+        let align = match layout.chars().next() {
+            Some('<') => Align::Left,
+            Some('^') => Align::Center,
+            Some('>') => Align::Right,
+            _ => Align::Left,
+        };
+        if matches!(layout.chars().next(), Some('<' | '^' | '>')) {
+            layout = &layout[1..];
+        }
+        let width = match layout {
+            "" => None,
+            digits => Some(
+                digits
+                    .parse()
+                    .map_err(|_| TemplateError::InvalidWidth(digits.to_string()))?,
+            ),
+        };
+
+        let mut style = None;
+        for name in styles {
+            style = Some(apply_style(style.unwrap_or_default(), name)?);
+        }
+
+        Ok(Field { key, align, width, style })
+    }
+
+    fn pad(&self, text: String) -> String {
+        // The bar renders to its own width, so only plain values get padded
+        let Some(width) = self.width.filter(|_| self.key != Key::Bar) else {
+            return text;
+        };
+        // Count terminal columns like the rest of the layout, not chars
+        let fill = width.saturating_sub(text_width(&text));
+        let (left, right) = match self.align {
+            Align::Left => (0, fill),
+            Align::Center => (fill / 2, fill - fill / 2),
+            Align::Right => (fill, 0),
+        };
+        format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+    }
+}
+
+fn apply_style(mut style: ContentStyle, name: &str) -> Result<ContentStyle, TemplateError> {
+    let attribute = match name {
+        "bold" => Some(Attribute::Bold),
+        "dim" => Some(Attribute::Dim),
+        "italic" => Some(Attribute::Italic),
+        "underline" => Some(Attribute::Underlined),
+        "reverse" => Some(Attribute::Reverse),
+        _ => None,
+    };
+    if let Some(attribute) = attribute {
+        style.attributes.set(attribute);
+        return Ok(style);
+    }
+
+    match name.strip_prefix("on_") {
+        Some(background) => style.background_color = Some(parse_color(background, name)?),
+        None => style.foreground_color = Some(parse_color(name, name)?),
+    }
+    Ok(style)
+}
+
+fn parse_color(color: &str, style: &str) -> Result<Color, TemplateError> {
+    Ok(match color {
+        "black" => Color::Black,
+        "red" => Color::Red,
+        "dark_red" => Color::DarkRed,
+        "green" => Color::Green,
+        "dark_green" => Color::DarkGreen,
+        "yellow" => Color::Yellow,
+        "dark_yellow" => Color::DarkYellow,
+        "blue" => Color::Blue,
+        "dark_blue" => Color::DarkBlue,
+        "magenta" => Color::Magenta,
+        "dark_magenta" => Color::DarkMagenta,
+        "cyan" => Color::Cyan,
+        "dark_cyan" => Color::DarkCyan,
+        "white" => Color::White,
+        "grey" => Color::Grey,
+        "dark_grey" => Color::DarkGrey,
+        _ => return Err(TemplateError::UnknownStyle(style.to_string())),
+    })
+}
@@ -0,0 +1,322 @@
+//! Test support: an in-memory terminal to snapshot progress output against.
+//!
+//! [`VirtualTerminal`] understands the escape sequences bars and throbbers emit,
+//! so tests can assert on what a user would actually see rather than on raw bytes.
+//! Combine it with tokio's paused clock, or a [`Clock::manual`](crate::Clock::manual)
+//! in the config, to step through animations frame by frame:
+//!
+//! ```rust
+//! use throbberous::{testing::{self, VirtualTerminal}, Bar, BarConfig};
+//! # #[cfg(feature = "tokio")]
+//! # tokio_test::block_on(async {
+//!
+//! let term = VirtualTerminal::new(60, 5);
+//! let bar = Bar::with_config(4, BarConfig::no_colors().with_target(term.target()));
+//! bar.inc(1);
+//! testing::settle().await;
+//! assert_eq!(term.screen(), "[==========                              ] 25% Quarter done");
+//! # });
+//! ```
+
+use std::{
+    fmt,
+    io::{self, Write},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+use unicode_width::UnicodeWidthChar;
+
+use crate::DrawTarget;
+
+/// Marks the second cell of a double-width character
+const CONTINUATION: char = '\0';
+
+/// An in-memory terminal emulator with a fixed size.
+///
+/// Clones share the same screen. Every flush that changes the screen records a
+/// frame, available through [`frames`](Self::frames).
+#[derive(Clone)]
+pub struct VirtualTerminal {
+    screen: Arc<Mutex<Screen>>,
+}
+
+impl VirtualTerminal {
+    /// A blank terminal `columns` wide and `rows` tall
+    pub fn new(columns: u16, rows: u16) -> Self {
+        Self { screen: Arc::new(Mutex::new(Screen::new(columns.max(1), rows.max(1)))) }
+    }
+
+    /// A draw target that writes to this terminal
+    pub fn target(&self) -> DrawTarget {
+        let columns = self.lock().columns as u16;
+        DrawTarget::terminal_with_width(self.clone(), columns)
+    }
+
+    /// The visible screen, one line per row, without trailing blanks or empty rows
+    pub fn screen(&self) -> String {
+        self.lock().render()
+    }
+
+    /// Every distinct screen seen at a flush, oldest first
+    pub fn frames(&self) -> Vec<String> {
+        self.lock().frames.clone()
+    }
+
+    /// Lines that scrolled off the top of the screen, oldest first
+    pub fn scrollback(&self) -> Vec<String> {
+        self.lock().scrollback.clone()
+    }
+
+    /// The cursor position as `(column, row)`, both starting at 0
+    pub fn cursor(&self) -> (usize, usize) {
+        let screen = self.lock();
+        (screen.column, screen.row)
+    }
+
+    /// Panic with both screens shown if the visible screen is not `expected`
+    #[track_caller]
+    pub fn assert_screen(&self, expected: &str) {
+        let actual = self.screen();
+        if actual != expected {
+            panic!("screen mismatch\n--- expected ---\n{}\n--- actual ---\n{}\n", expected, actual);
+        }
+    }
+
+    /// Panic unless the recorded frames are exactly `expected`, in order
+    #[track_caller]
+    pub fn assert_frames(&self, expected: &[&str]) {
+        let actual = self.frames();
+        if actual != expected {
+            panic!("frame mismatch\n--- expected ---\n{}\n--- actual ---\n{}\n", expected.join("\n~~~\n"), actual.join("\n~~~\n"));
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Screen> {
+        self.screen.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Write for VirtualTerminal {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.lock().feed(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.lock().snapshot();
+        Ok(())
+    }
+}
+
+impl fmt::Debug for VirtualTerminal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VirtualTerminal").field("screen", &self.screen()).finish()
+    }
+}
+
+/// Let spawned draw tasks catch up with the updates made so far.
+///
+/// This only waits for tasks on the same runtime, so it needs the `tokio`
+/// feature; draw tasks on threads of their own keep their own pace.
+pub async fn settle() {
+    for _ in 0..16 {
+        crate::rt::yield_now().await;
+    }
+}
+
+/// Move time forward by `duration`, then let draw tasks catch up.
+///
+/// With tokio's clock paused (`#[tokio::test(start_paused = true)]`) this is
+/// instant and every timer in between fires in order.
+pub async fn step(duration: Duration) {
+    crate::rt::sleep(duration).await;
+    settle().await;
+}
+
+struct Screen {
+    columns: usize,
+    cells: Vec<Vec<char>>,
+    row: usize,
+    column: usize,
+    wrap_pending: bool,
+    pending: Vec<u8>, // bytes of an unfinished escape sequence or character
+    scrollback: Vec<String>,
+    frames: Vec<String>,
+}
+
+impl Screen {
+    fn new(columns: u16, rows: u16) -> Self {
+        let columns = columns as usize;
+        Self {
+            columns,
+            cells: vec![vec![' '; columns]; rows as usize],
+            row: 0,
+            column: 0,
+            wrap_pending: false,
+            pending: Vec::new(),
+            scrollback: Vec::new(),
+            frames: Vec::new(),
+        }
+    }
+
+    fn feed(&mut self, bytes: &[u8]) {
+        self.pending.extend_from_slice(bytes);
+        let input = std::mem::take(&mut self.pending);
+        let mut rest = &input[..];
+
+        while !rest.is_empty() {
+            let consumed = match rest[0] {
+                0x1b => match self.escape(rest) {
+                    Some(len) => len,
+                    None => break, // wait for the rest of the sequence
+                },
+                b'\n' => {
+                    self.line_feed();
+                    1
+                }
+                b'\r' => {
+                    self.column = 0;
+                    self.wrap_pending = false;
+                    1
+                }
+                _ => {
+                    let len = utf8_len(rest[0]);
+                    if rest.len() < len {
+                        break;
+                    }
+                    match std::str::from_utf8(&rest[..len]) {
+                        Ok(text) => text.chars().for_each(|c| self.print(c)),
+                        Err(_) => self.print(char::REPLACEMENT_CHARACTER),
+                    }
+                    len
+                }
+            };
+            rest = &rest[consumed..];
+        }
+        self.pending = rest.to_vec();
+    }
+
+    /// Apply the escape sequence at the start of `input`, returning its length,
+    /// or `None` if it is incomplete
+    fn escape(&mut self, input: &[u8]) -> Option<usize> {
+        match input.get(1)? {
+            b'[' => {}
+            _ => return Some(2), // lone escapes are not used by this crate
+        }
+        let end = input[2..].iter().position(|b| (0x40..=0x7e).contains(b))? + 2;
+        let params = std::str::from_utf8(&input[2..end]).unwrap_or_default();
+        let private = params.starts_with('?');
+        let args: Vec<usize> = params
+            .trim_start_matches('?')
+            .split(';')
+            .map(|arg| arg.parse().unwrap_or(0))
+            .collect();
+        let n = args.first().copied().unwrap_or(0).max(1);
+
+        if !private {
+            match input[end] {
+                b'A' => self.move_to(self.column, self.row.saturating_sub(n)),
+                b'B' => self.move_to(self.column, self.row + n),
+                b'C' => self.move_to(self.column + n, self.row),
+                b'D' => self.move_to(self.column.saturating_sub(n), self.row),
+                b'E' => self.move_to(0, self.row + n),
+                b'F' => self.move_to(0, self.row.saturating_sub(n)),
+                b'G' => self.move_to(n - 1, self.row),
+                b'H' => self.move_to(args.get(1).copied().unwrap_or(0).max(1) - 1, n - 1),
+                b'J' => self.clear_screen(args[0]),
+                b'K' => self.clear_line(args[0]),
+                _ => {} // styles and anything else leave the text alone
+            }
+        }
+        Some(end + 1)
+    }
+
+    fn print(&mut self, c: char) {
+        let width = c.width().unwrap_or(0);
+        if width == 0 {
+            return;
+        }
+        if self.wrap_pending || self.column + width > self.columns {
+            self.line_feed();
+        }
+        let row = &mut self.cells[self.row];
+        row[self.column] = c;
+        if width == 2 && self.column + 1 < self.columns {
+            row[self.column + 1] = CONTINUATION;
+        }
+        self.column += width;
+        if self.column >= self.columns {
+            self.column = self.columns - 1;
+            self.wrap_pending = true;
+        }
+    }
+
+    fn line_feed(&mut self) {
+        self.column = 0;
+        self.wrap_pending = false;
+        if self.row + 1 < self.cells.len() {
+            self.row += 1;
+        } else {
+            let top = self.cells.remove(0);
+            self.scrollback.push(render_row(&top));
+            self.cells.push(vec![' '; self.columns]);
+        }
+    }
+
+    fn move_to(&mut self, column: usize, row: usize) {
+        self.column = column.min(self.columns - 1);
+        self.row = row.min(self.cells.len() - 1);
+        self.wrap_pending = false;
+    }
+
+    fn clear_line(&mut self, mode: usize) {
+        let row = &mut self.cells[self.row];
+        let range = match mode {
+            0 => self.column..self.columns,
+            1 => 0..self.column + 1,
+            _ => 0..self.columns,
+        };
+        row[range].fill(' ');
+    }
+
+    fn clear_screen(&mut self, mode: usize) {
+        let rows = match mode {
+            0 => self.row + 1..self.cells.len(),
+            1 => 0..self.row,
+            _ => 0..self.cells.len(),
+        };
+        for row in &mut self.cells[rows] {
+            row.fill(' ');
+        }
+        self.clear_line(mode);
+    }
+
+    fn render(&self) -> String {
+        let mut rows: Vec<String> = self.cells.iter().map(|row| render_row(row)).collect();
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+        rows.join("\n")
+    }
+
+    fn snapshot(&mut self) {
+        let screen = self.render();
+        if self.frames.last() != Some(&screen) {
+            self.frames.push(screen);
+        }
+    }
+}
+
+fn render_row(row: &[char]) -> String {
+    let text: String = row.iter().filter(|&&c| c != CONTINUATION).collect();
+    text.trim_end().to_string()
+}
+
+fn utf8_len(first: u8) -> usize {
+    match first {
+        0xf0.. => 4,
+        0xe0.. => 3,
+        0xc0.. => 2,
+        _ => 1,
+    }
+}
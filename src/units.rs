@@ -0,0 +1,63 @@
+//! Human-readable formatting of a bar's position, total and rate.
+
+use std::borrow::Cow;
+
+/// What a [`Bar`](crate::Bar) counts, used for the `{pos}`, `{len}` and `{per_sec}` fields
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Unit {
+    /// Plain numbers, e.g. `1234` and `12.5/s`
+    #[default]
+    Count,
+    /// Bytes in powers of 1024, e.g. `312.4 MiB` and `48.0 MiB/s`
+    Bytes,
+    /// Bytes in powers of 1000, e.g. `327.6 MB` and `50.3 MB/s`
+    DecimalBytes,
+    /// Numbers followed by a label, e.g. `12 files` and `3.4 files/s`
+    Label(Cow<'static, str>),
+}
+
+const BINARY: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const DECIMAL: [&str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+
+impl Unit {
+    /// Count items with a custom label, e.g. `Unit::label("rows")`
+    pub fn label(label: impl Into<Cow<'static, str>>) -> Self {
+        Unit::Label(label.into())
+    }
+
+    /// Format an amount, e.g. a position or total
+    pub fn format(&self, amount: u64) -> String {
+        match self {
+            Unit::Count => amount.to_string(),
+            Unit::Bytes => scaled(amount as f64, 1024.0, &BINARY, false),
+            Unit::DecimalBytes => scaled(amount as f64, 1000.0, &DECIMAL, false),
+            Unit::Label(label) => format!("{} {}", amount, label),
+        }
+    }
+
+    /// Format a rate in units per second
+    pub fn format_rate(&self, per_sec: f64) -> String {
+        match self {
+            Unit::Count => format!("{:.1}/s", per_sec),
+            Unit::Bytes => format!("{}/s", scaled(per_sec, 1024.0, &BINARY, true)),
+            Unit::DecimalBytes => format!("{}/s", scaled(per_sec, 1000.0, &DECIMAL, true)),
+            Unit::Label(label) => format!("{:.1} {}/s", per_sec, label),
+        }
+    }
+}
+
+/// Scale `value` down to the largest prefix that keeps it at or above 1.
+///
+/// Whole bytes print without decimals unless `fractional` (rates can be fractional).
+fn scaled(mut value: f64, base: f64, suffixes: &[&str], fractional: bool) -> String {
+    let mut index = 0;
+    while value >= base && index < suffixes.len() - 1 {
+        value /= base;
+        index += 1;
+    }
+    if index == 0 && !fractional {
+        format!("{} {}", value, suffixes[0])
+    } else {
+        format!("{:.1} {}", value, suffixes[index])
+    }
+}
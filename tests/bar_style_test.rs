@@ -0,0 +1,54 @@
+mod common;
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, BarGlyphs, BarStyle, DrawTarget, Template};
+use tokio::time::{sleep, Duration};
+
+async fn render(style: BarStyle, pos: u64) -> String {
+    let buf = SharedBuf::default();
+    let config = BarConfig::no_colors()
+        .with_target(DrawTarget::terminal(buf.clone()))
+        .with_template(Template::parse("{bar:10}").unwrap())
+        .with_style(style);
+    let bar = Bar::with_config(100, config);
+    bar.set_position(pos).await;
+    sleep(Duration::from_millis(20)).await;
+    buf.last_line()
+}
+
+#[tokio::test]
+async fn test_classic_fills_whole_cells() {
+    assert_eq!(render(BarStyle::Classic, 13).await, "[=         ]");
+}
+
+#[tokio::test]
+async fn test_smooth_shows_eighths() {
+    assert_eq!(render(BarStyle::Smooth, 13).await, "[█▎        ]");
+    assert_eq!(render(BarStyle::Smooth, 55).await, "[█████▌    ]");
+}
+
+#[tokio::test]
+async fn test_smooth_ascii_shows_quarters() {
+    assert_eq!(render(BarStyle::SmoothAscii, 13).await, "[#.        ]");
+    assert_eq!(render(BarStyle::SmoothAscii, 97).await, "[#########=]");
+}
+
+#[tokio::test]
+async fn test_glyph_presets() {
+    async fn render_glyphs(glyphs: BarGlyphs, pos: u64) -> String {
+        let buf = SharedBuf::default();
+        let config = BarConfig::no_colors()
+            .with_target(DrawTarget::terminal(buf.clone()))
+            .with_template(Template::parse("{bar:10}").unwrap())
+            .with_glyphs(glyphs);
+        let bar = Bar::with_config(100, config);
+        bar.set_position(pos).await;
+        sleep(Duration::from_millis(20)).await;
+        buf.last_line()
+    }
+
+    assert_eq!(render_glyphs(BarGlyphs::hashes(), 50).await, "[####>.....]");
+    assert_eq!(render_glyphs(BarGlyphs::line(), 40).await, "━━━╸──────");
+    assert_eq!(render_glyphs(BarGlyphs::arrow(), 100).await, "[==========]\n");
+    assert_eq!(render_glyphs(BarGlyphs::blocks(), 0).await, "░░░░░░░░░░");
+}
@@ -1,10 +1,23 @@
-#[tokio::test]
+#![cfg(feature = "tokio")]
+
+use throbberous::{testing::{self, VirtualTerminal}, Bar, BarConfig};
+use tokio::time::Duration;
+
+#[tokio::test(start_paused = true)]
 async fn test_bar() {
-    let bar = throbberous::Bar::new(100);
+    let term = VirtualTerminal::new(60, 4);
+    let bar = Bar::with_config(100, BarConfig::no_colors().with_target(term.target()));
     for _ in 0..100 {
-        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        testing::step(Duration::from_millis(50)).await;
         bar.inc(1).await;
     }
     bar.finish_with_message("Done!").await;
-}
+    testing::settle().await;
 
+    let frames = term.frames();
+    assert_eq!(frames.len(), 100, "one frame per step");
+    assert_eq!(frames[0], "[                                        ] 1% Working...");
+    assert_eq!(frames[49], "[====================                    ] 50% Working...");
+    term.assert_screen("[========================================] 100% Done!");
+    assert_eq!(term.cursor(), (0, 1), "finished bar should move past its line");
+}
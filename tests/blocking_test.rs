@@ -0,0 +1,29 @@
+use throbberous::{blocking, testing::VirtualTerminal, BarConfig, MultiProgress, ThrobberConfig};
+
+#[test]
+fn test_blocking_bar_without_runtime() {
+    let term = VirtualTerminal::new(60, 3);
+    let bar = blocking::Bar::with_config(4, BarConfig::no_colors().with_target(term.target()));
+    bar.set_message("syncing");
+    bar.inc(2);
+    bar.finish();
+
+    term.assert_screen("[========================================] 100% syncing");
+    assert_eq!(term.cursor(), (0, 1));
+}
+
+#[test]
+fn test_blocking_throbber_in_multi_progress() {
+    let term = VirtualTerminal::new(30, 4);
+    let multi = MultiProgress::with_target(term.target());
+    let throbber = multi.add(blocking::Throbber::with_config(ThrobberConfig { frame_delay: 10, ..ThrobberConfig::no_colors() }));
+    throbber.set_message("indexing");
+    let spinning = throbber.start();
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    assert!(term.frames().iter().any(|frame| frame.ends_with(" indexing")));
+
+    throbber.println("found 3 crates");
+    spinning.stop();
+    assert!(term.screen().starts_with("found 3 crates"), "unexpected screen: {:?}", term.screen());
+    assert!(!term.screen().contains("indexing"));
+}
@@ -0,0 +1,62 @@
+use throbberous::{Bar, BarConfig, Clock, DrawTarget};
+#[cfg(feature = "tokio")]
+use throbberous::{testing::{self, VirtualTerminal}, Throbber, ThrobberConfig};
+use tokio::time::Duration;
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_manual_clock_drives_animation() {
+    let clock = Clock::manual();
+    let term = VirtualTerminal::new(20, 2);
+    let config = ThrobberConfig::no_colors().with_target(term.target()).with_clock(clock.clone());
+    let throbber = Throbber::with_config(config);
+    throbber.set_message("waiting").await;
+    let _spinning = throbber.start().await;
+
+    // Real time passing does nothing while the clock stands still
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(term.frames().is_empty());
+
+    for _ in 0..2 {
+        clock.advance(Duration::from_millis(150));
+        testing::settle().await;
+    }
+    term.assert_frames(&["/ waiting", "- waiting"]);
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_throbber_started_late_still_animates() {
+    let clock = Clock::manual();
+    let term = VirtualTerminal::new(20, 2);
+    let config = ThrobberConfig::no_colors().with_target(term.target()).with_clock(clock.clone());
+    let throbber = Throbber::with_config(config);
+    throbber.set_message("waiting").await;
+    for _ in 0..3 {
+        clock.advance(Duration::from_millis(150));
+        testing::settle().await;
+    }
+
+    let _spinning = throbber.start().await;
+    for _ in 0..2 {
+        clock.advance(Duration::from_millis(150));
+        testing::settle().await;
+    }
+    term.assert_frames(&["/ waiting", "- waiting"]);
+}
+
+#[tokio::test]
+async fn test_manual_clock_gives_exact_estimates() {
+    let clock = Clock::manual();
+    let config = BarConfig::no_colors().with_target(DrawTarget::hidden()).with_clock(clock.clone());
+    let bar = Bar::with_config(100, config);
+
+    clock.advance(Duration::from_secs(2));
+    bar.set_position(20).await;
+    clock.advance(Duration::from_secs(2));
+    bar.set_position(40).await;
+
+    assert_eq!(bar.elapsed().await, Duration::from_secs(4));
+    assert_eq!(bar.rate().await, 10.0);
+    assert_eq!(bar.eta().await, Some(Duration::from_secs(6)));
+}
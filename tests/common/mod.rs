@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+/// An in-memory writer that can be handed to a `DrawTarget` and inspected later
+#[derive(Clone, Default)]
+pub struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl SharedBuf {
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+    }
+
+    /// The most recently drawn line, i.e. everything after the last "clear below cursor"
+    pub fn last_line(&self) -> String {
+        self.contents().rsplit("\x1b[J").next().unwrap_or_default().to_string()
+    }
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, DrawTarget};
+#[cfg(feature = "tokio")]
+use throbberous::LineFallback;
+
+#[tokio::test]
+async fn test_bar_draws_to_writer() {
+    let buf = SharedBuf::default();
+    let config = BarConfig::no_colors().with_target(DrawTarget::writer(buf.clone()));
+    let bar = Bar::with_config(10, config);
+    for _ in 0..5 {
+        bar.inc(1).await;
+        tokio::task::yield_now().await;
+    }
+    bar.finish_with_message("Done!").await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    let output = buf.contents();
+    assert!(output.contains("100% Done!"), "unexpected output: {output:?}");
+    assert!(output.ends_with('\n'));
+}
+
+#[tokio::test]
+async fn test_hidden_target_draws_nothing() {
+    let config = BarConfig::no_colors().with_target(DrawTarget::hidden());
+    let bar = Bar::with_config(10, config);
+    assert!(bar.draw_target().is_hidden());
+    bar.inc(5).await;
+    bar.finish().await;
+
+    let buf = SharedBuf::default();
+    let bar = Bar::with_config(10, BarConfig::no_colors().with_target(DrawTarget::writer(buf.clone())));
+    bar.inc(2).await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    let drawn = buf.contents();
+    assert!(!drawn.is_empty());
+
+    bar.set_draw_target(DrawTarget::hidden());
+    assert!(bar.draw_target().is_hidden());
+    bar.inc(5).await;
+    bar.finish().await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+    assert_eq!(buf.contents(), drawn);
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_non_terminal_gets_plain_lines() {
+    let buf = SharedBuf::default();
+    let fallback = LineFallback { interval: 60_000, percent_step: 25 };
+    let config = BarConfig {
+        fallback: Some(fallback),
+        ..BarConfig::default().with_target(DrawTarget::writer(buf.clone()))
+    };
+    let bar = Bar::with_config(100, config);
+    bar.set_message("copying").await;
+    for _ in 0..60 {
+        bar.inc(1).await;
+        tokio::task::yield_now().await;
+    }
+    bar.finish_with_message("copied").await;
+    tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+
+    let output = buf.contents();
+    assert!(!output.contains('\x1b'), "escape codes in plain output: {output:?}");
+    let percents: Vec<&str> = output
+        .lines()
+        .map(|line| line.split("] ").nth(1).unwrap().split(' ').next().unwrap())
+        .collect();
+    // The first line is whatever position the bar had reached when it first drew
+    assert_eq!(percents.len(), 4, "unexpected output: {output:?}");
+    assert_eq!(percents[1..], ["25%", "50%", "100%"]);
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "tokio")]
+
+use throbberous::{testing::{self, VirtualTerminal}, Bar, BarConfig, OnDrop, Throbber, ThrobberConfig};
+use tokio::time::Duration;
+
+fn dropped_at_three(on_drop: OnDrop) -> VirtualTerminal {
+    let term = VirtualTerminal::new(80, 3);
+    let bar = Bar::with_config(10, BarConfig::no_colors().with_target(term.target()).with_on_drop(on_drop));
+    bar.set_position(3);
+    drop(bar);
+    term
+}
+
+#[tokio::test]
+async fn test_dropped_bar_is_abandoned_or_cleared() {
+    let abandoned = dropped_at_three(OnDrop::Abandon);
+    let cleared = dropped_at_three(OnDrop::Clear);
+    testing::settle().await;
+
+    abandoned.assert_screen("⊘ [============                            ] 30% Quarter done");
+    assert_eq!(abandoned.cursor(), (0, 1));
+    cleared.assert_screen("");
+    assert_eq!(cleared.cursor(), (0, 0));
+}
+
+#[tokio::test]
+async fn test_dropped_bar_is_abandoned_by_default_unless_complete() {
+    let term = dropped_at_three(OnDrop::default());
+    testing::settle().await;
+    term.assert_screen("⊘ [============                            ] 30% Quarter done");
+
+    let complete = VirtualTerminal::new(80, 3);
+    let bar = Bar::with_config(10, BarConfig::no_colors().with_target(complete.target()).with_on_drop(OnDrop::Clear));
+    bar.set_position(10);
+    drop(bar);
+    testing::settle().await;
+    complete.assert_screen("[========================================] 100% Complete!");
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_throbber_stops_when_guard_is_dropped() {
+    let term = VirtualTerminal::new(20, 3);
+    let throbber = Throbber::with_config(ThrobberConfig { frame_delay: 100, ..ThrobberConfig::no_colors().with_target(term.target()) });
+    throbber.set_message("loading").await;
+    {
+        let _spinning = throbber.start().await;
+        testing::step(Duration::from_millis(100)).await;
+        term.assert_screen("/ loading");
+    }
+    testing::step(Duration::from_millis(100)).await;
+    assert!(!term.screen().contains("loading"), "still spinning: {:?}", term.screen());
+}
+
+#[test]
+fn test_drops_draw_before_the_runtime_shuts_down() {
+    let bar_term = VirtualTerminal::new(80, 3);
+    let throbber_term = VirtualTerminal::new(20, 3);
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+    runtime.block_on(async {
+        let throbber = Throbber::with_config(ThrobberConfig { frame_delay: 20, ..ThrobberConfig::no_colors().with_target(throbber_term.target()) });
+        throbber.set_message("loading").await;
+        let _spinning = throbber.start().await;
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(throbber_term.screen().contains("loading"));
+
+        let bar = Bar::with_config(10, BarConfig::no_colors().with_target(bar_term.target()));
+        bar.set_position(3);
+        // Returning drops both, and the runtime goes right after
+    });
+    drop(runtime);
+
+    bar_term.assert_screen("⊘ [============                            ] 30% Quarter done");
+    assert_eq!(bar_term.cursor(), (0, 1));
+    assert!(!throbber_term.screen().contains("loading"), "still spinning: {:?}", throbber_term.screen());
+}
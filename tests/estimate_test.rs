@@ -0,0 +1,35 @@
+mod common;
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, DrawTarget, Template};
+use tokio::time::{sleep, Duration};
+
+#[tokio::test]
+async fn test_rate_and_eta() {
+    let bar = Bar::with_config(100, BarConfig::no_colors().with_target(DrawTarget::hidden()));
+    assert_eq!(bar.eta().await, None);
+
+    for _ in 0..10 {
+        sleep(Duration::from_millis(20)).await;
+        bar.inc(1).await;
+    }
+
+    assert!(bar.elapsed().await >= Duration::from_millis(200));
+    let rate = bar.rate().await;
+    assert!(rate > 10.0 && rate < 100.0, "unexpected rate {rate}");
+    let eta = bar.eta().await.expect("eta once progress was made");
+    assert!(eta > Duration::from_millis(500) && eta < Duration::from_secs(20), "unexpected eta {eta:?}");
+}
+
+#[tokio::test]
+async fn test_time_fields_render() {
+    let buf = SharedBuf::default();
+    let template = Template::parse("{elapsed} {eta} {per_sec}").unwrap();
+    let config = BarConfig::no_colors()
+        .with_target(DrawTarget::terminal(buf.clone()))
+        .with_template(template);
+    let bar = Bar::with_config(10, config);
+    bar.set_message("start").await;
+    sleep(Duration::from_millis(20)).await;
+    assert!(buf.contents().ends_with("00:00 --:-- 0.0/s"), "unexpected output: {:?}", buf.contents());
+}
@@ -0,0 +1,41 @@
+use throbberous::{blocking, Bar, Throbber};
+#[cfg(feature = "tokio")]
+use throbberous::{testing::{self, VirtualTerminal}, BarConfig};
+
+fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+
+#[test]
+fn test_handles_are_shareable() {
+    assert_shareable::<Bar>();
+    assert_shareable::<Throbber>();
+    assert_shareable::<blocking::Bar>();
+    assert_shareable::<blocking::Throbber>();
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_clones_share_one_bar_until_the_last_is_dropped() {
+    let term = VirtualTerminal::new(60, 3);
+    let bar = Bar::with_config(100, BarConfig::no_colors().with_target(term.target()));
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let bar = bar.clone();
+            tokio::spawn(async move {
+                for _ in 0..10 {
+                    bar.inc(1);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.await.unwrap();
+    }
+    assert_eq!(bar.position(), 40);
+    testing::settle().await;
+    assert_eq!(term.cursor().1, 0, "bar finished while a handle was alive");
+
+    drop(bar);
+    testing::settle().await;
+    assert!(term.screen().starts_with("⊘ [================                       ] 40% "), "unexpected screen: {:?}", term.screen());
+    assert_eq!(term.cursor(), (0, 1));
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use std::io::{Read, Write};
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, DrawTarget, ProgressReader, ProgressWriter};
+use tokio::time::{sleep, Duration};
+
+fn bar(total: u64, buf: &SharedBuf) -> Bar {
+    Bar::with_config(total, BarConfig::no_colors().with_target(DrawTarget::terminal_with_width(buf.clone(), 40)))
+}
+
+#[tokio::test]
+async fn test_async_copy_counts_bytes() {
+    let buf = SharedBuf::default();
+    let data = vec![7u8; 1000];
+    let mut reader = ProgressReader::new(&data[..], bar(1000, &buf));
+    let mut copied = Vec::new();
+    tokio::io::copy(&mut reader, &mut copied).await.unwrap();
+    assert_eq!(copied, data);
+    sleep(Duration::from_millis(20)).await;
+
+    let contents = buf.contents();
+    assert!(contents.contains("] 100% "), "unexpected output: {contents:?}");
+    assert!(contents.ends_with('\n'), "bar was not finished: {contents:?}");
+}
+
+#[tokio::test]
+async fn test_std_writer_counts_partial_progress() {
+    let buf = SharedBuf::default();
+    let mut writer = ProgressWriter::new(Vec::new(), bar(100, &buf));
+    writer.write_all(&[0; 25]).unwrap();
+    sleep(Duration::from_millis(20)).await;
+
+    assert!(buf.last_line().contains("] 25% "), "unexpected line: {:?}", buf.last_line());
+    assert_eq!(writer.into_inner().len(), 25);
+}
+
+#[tokio::test]
+async fn test_file_total_from_metadata() {
+    let path = std::env::temp_dir().join(format!("throbberous-io-test-{}", std::process::id()));
+    std::fs::write(&path, [1u8; 300]).unwrap();
+
+    let mut reader = ProgressReader::std_file(std::fs::File::open(&path).unwrap()).unwrap();
+    reader.bar().set_draw_target(DrawTarget::hidden());
+    assert_eq!(reader.bar().length(), 300);
+    let mut contents = Vec::new();
+    reader.read_to_end(&mut contents).unwrap();
+    assert_eq!(contents.len(), 300);
+    assert_eq!(reader.bar().position(), 300);
+
+    #[cfg(feature = "tokio")]
+    {
+        use tokio::io::AsyncReadExt;
+
+        let mut reader = ProgressReader::file(tokio::fs::File::open(&path).await.unwrap()).await.unwrap();
+        reader.bar().set_draw_target(DrawTarget::hidden());
+        assert_eq!(reader.bar().length(), 300);
+        let mut contents = Vec::new();
+        reader.read_to_end(&mut contents).await.unwrap();
+        assert_eq!(contents.len(), 300);
+        assert_eq!(reader.bar().position(), 300);
+    }
+    std::fs::remove_file(&path).unwrap();
+}
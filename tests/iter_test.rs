@@ -0,0 +1,50 @@
+mod common;
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, DrawTarget, ProgressIterator, ProgressStream};
+use tokio::time::{sleep, Duration};
+use tokio_stream::StreamExt;
+
+fn config(buf: &SharedBuf) -> BarConfig {
+    BarConfig::no_colors().with_target(DrawTarget::terminal_with_width(buf.clone(), 40))
+}
+
+#[tokio::test]
+async fn test_iterator_drives_bar_to_completion() {
+    let buf = SharedBuf::default();
+    let items: Vec<u32> = vec![1, 2, 3, 4].into_iter()
+        .progress_with(Bar::with_config(4, config(&buf)))
+        .collect();
+    assert_eq!(items, [1, 2, 3, 4]);
+    sleep(Duration::from_millis(20)).await;
+
+    let contents = buf.contents();
+    assert!(contents.contains("100% Complete!"), "unexpected output: {contents:?}");
+    assert!(contents.ends_with('\n'), "bar was not finished: {contents:?}");
+}
+
+#[tokio::test]
+async fn test_stream_of_unknown_length_finishes() {
+    let buf = SharedBuf::default();
+    let stream = tokio_stream::iter(0..10).filter(|n| n % 2 == 0);
+    let bar = Bar::indeterminate_with_config("evens", config(&buf));
+    let evens: Vec<i32> = stream.progress_with(bar).collect().await;
+    assert_eq!(evens, [0, 2, 4, 6, 8]);
+    sleep(Duration::from_millis(20)).await;
+
+    let contents = buf.contents();
+    assert!(contents.contains("evens"), "unexpected output: {contents:?}");
+    assert!(contents.ends_with('\n'), "bar was not finished: {contents:?}");
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_async_updates_see_pending_increments() {
+    let buf = SharedBuf::default();
+    let mut iter = (0..10).progress_with(Bar::with_config(10, config(&buf)));
+    iter.by_ref().take(3).for_each(drop);
+    iter.bar().inc(2).await;
+    sleep(Duration::from_millis(20)).await;
+
+    assert!(buf.last_line().contains("] 50% "), "unexpected line: {:?}", buf.last_line());
+}
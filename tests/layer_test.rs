@@ -0,0 +1,33 @@
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use common::SharedBuf;
+use throbberous::{DrawTarget, ProgressLayer, ThrobberConfig};
+use tokio::time::{sleep, Duration};
+use tracing_subscriber::layer::SubscriberExt;
+
+#[tokio::test]
+async fn test_progress_span_shows_throbber_until_closed() {
+    let buf = SharedBuf::default();
+    let layer = ProgressLayer::new()
+        .with_config(ThrobberConfig { frame_delay: 10, ..ThrobberConfig::no_colors() })
+        .with_target(DrawTarget::terminal_with_width(buf.clone(), 60));
+    let _default = tracing::subscriber::set_default(tracing_subscriber::registry().with(layer));
+
+    let span = tracing::info_span!("fetch", progress = true, url = "example.com", attempt = 2);
+    let quiet = tracing::info_span!("parse");
+    {
+        let _entered = span.enter();
+        let _quiet = quiet.enter();
+        sleep(Duration::from_millis(50)).await;
+    }
+    let drawn = buf.contents();
+    assert!(drawn.contains("fetch url=example.com attempt=2"), "no throbber: {drawn:?}");
+    assert!(!drawn.contains("parse"), "span without progress got a throbber: {drawn:?}");
+
+    drop(span);
+    sleep(Duration::from_millis(20)).await;
+    let output = buf.contents();
+    assert!(output.contains("fetch url=example.com attempt=2 done in "), "no summary: {output:?}");
+}
@@ -0,0 +1,74 @@
+#![cfg(any(feature = "log", feature = "tracing"))]
+
+mod common;
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, DrawTarget};
+use tokio::time::{sleep, Duration};
+
+async fn drawn_bar(target: &DrawTarget) -> Bar {
+    let bar = Bar::with_config(10, BarConfig::no_colors().with_target(target.clone()));
+    bar.set_message("working").await;
+    sleep(Duration::from_millis(20)).await;
+    bar
+}
+
+/// Asserts `record` was written on a cleared line and the bar came back after it
+fn assert_above_bar(buf: &SharedBuf, record: &str) {
+    let output = buf.contents();
+    let (before, after) = output.rsplit_once(record).expect("record was written");
+    assert!(before.ends_with("\x1b[J"), "bar was not erased first: {output:?}");
+    assert!(after.ends_with("] 0% working"), "bar was not redrawn: {output:?}");
+}
+
+#[cfg(feature = "log")]
+#[tokio::test]
+async fn test_log_records_print_above_bar() {
+    use std::io::Write;
+    use throbberous::LogWrapper;
+
+    struct BufLogger(SharedBuf);
+
+    impl log::Log for BufLogger {
+        fn enabled(&self, _: &log::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record<'_>) {
+            writeln!(self.0.clone(), "{} {}", record.level(), record.args()).unwrap();
+        }
+
+        fn flush(&self) {}
+    }
+
+    let buf = SharedBuf::default();
+    let target = DrawTarget::terminal_with_width(buf.clone(), 40);
+    let _bar = drawn_bar(&target).await;
+
+    LogWrapper::new(target, BufLogger(buf.clone())).try_init().unwrap();
+    log::set_max_level(log::LevelFilter::Info);
+    log::info!("fetched index");
+
+    assert_above_bar(&buf, "INFO fetched index\n");
+}
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn test_tracing_events_print_above_bar() {
+    use throbberous::TracingWriter;
+
+    let buf = SharedBuf::default();
+    let target = DrawTarget::terminal_with_width(buf.clone(), 40);
+    let _bar = drawn_bar(&target).await;
+
+    let writer = buf.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .without_time()
+        .with_ansi(false)
+        .with_target(false)
+        .with_writer(TracingWriter::new(target, move || writer.clone()))
+        .finish();
+    tracing::subscriber::with_default(subscriber, || tracing::warn!("disk almost full"));
+
+    assert_above_bar(&buf, " WARN disk almost full\n");
+}
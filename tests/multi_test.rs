@@ -0,0 +1,50 @@
+mod common;
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, DrawTarget, MultiProgress};
+use tokio::time::{sleep, Duration};
+
+#[tokio::test]
+async fn test_multi_stacks_rows() {
+    let buf = SharedBuf::default();
+    let multi = MultiProgress::with_target(DrawTarget::terminal(buf.clone()));
+    let unthrottled = || BarConfig { max_refresh_rate: None, ..BarConfig::no_colors() };
+    let first = multi.add(Bar::with_config(10, unthrottled()));
+    let second = multi.add(Bar::with_config(10, unthrottled()));
+
+    first.set_message("first").await;
+    sleep(Duration::from_millis(20)).await;
+    second.set_message("second").await;
+    sleep(Duration::from_millis(20)).await;
+
+    let output = buf.contents();
+    assert!(output.contains("0% first\n[") && output.ends_with("0% second"), "unexpected output: {output:?}");
+    // Redrawing two rows moves the cursor up one line first
+    assert!(output.contains("\x1b[1A"));
+
+    first.finish().await;
+    second.finish().await;
+    sleep(Duration::from_millis(20)).await;
+    assert!(buf.contents().ends_with("100% second\n"));
+}
+
+#[tokio::test]
+async fn test_multi_remove_drops_row() {
+    let buf = SharedBuf::default();
+    let multi = MultiProgress::with_target(DrawTarget::terminal(buf.clone()));
+    let kept = multi.add(Bar::with_config(10, BarConfig::no_colors()));
+    let removed = multi.insert(0, Bar::with_config(10, BarConfig::no_colors()));
+
+    removed.set_message("removed").await;
+    kept.set_message("kept").await;
+    sleep(Duration::from_millis(20)).await;
+
+    multi.remove(&removed);
+    assert!(removed.draw_target().is_hidden());
+    let before = buf.contents().len();
+    removed.set_message("gone").await;
+    sleep(Duration::from_millis(20)).await;
+
+    let tail = &buf.contents()[before..];
+    assert!(!tail.contains("gone"), "removed bar still drawn: {tail:?}");
+}
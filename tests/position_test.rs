@@ -0,0 +1,50 @@
+#![cfg(feature = "tokio")]
+
+mod common;
+
+use common::SharedBuf;
+use throbberous::{testing::{self, VirtualTerminal}, Bar, BarConfig, DrawTarget, Status};
+
+#[tokio::test]
+async fn test_inc_from_many_threads_without_await() {
+    let bar = Bar::with_config(40_000, BarConfig::no_colors().with_target(DrawTarget::hidden()));
+    std::thread::scope(|scope| {
+        for _ in 0..4 {
+            scope.spawn(|| {
+                for _ in 0..10_000 {
+                    bar.inc(1);
+                }
+            });
+        }
+    });
+    assert_eq!(bar.position(), 40_000);
+    assert_eq!(bar.length(), 40_000);
+}
+
+#[tokio::test]
+async fn test_position_and_length_are_sampled_on_redraw() {
+    let term = VirtualTerminal::new(60, 3);
+    let bar = Bar::with_config(10, BarConfig::no_colors().with_target(term.target()));
+    bar.set_position(8).await;
+    bar.set_length(16);
+    testing::settle().await;
+    term.assert_screen("[====================                    ] 50% Halfway done");
+
+    bar.set_position(20);
+    assert_eq!(bar.position(), 16);
+    testing::settle().await;
+    term.assert_screen("[========================================] 100% Halfway done");
+}
+
+#[tokio::test]
+async fn test_shrinking_length_to_position_finishes() {
+    let buf = SharedBuf::default();
+    let bar = Bar::with_config(10, BarConfig::no_colors().with_target(DrawTarget::terminal(buf.clone())));
+    bar.set_position(5).await;
+    testing::settle().await;
+    bar.set_length(5);
+    testing::settle().await;
+    assert_eq!(bar.status(), Status::Finished);
+    let output = buf.contents();
+    assert!(output.ends_with("100% Halfway done\n"), "unexpected output: {output:?}");
+}
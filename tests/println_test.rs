@@ -0,0 +1,44 @@
+mod common;
+
+use std::io::Write;
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, DrawTarget};
+use tokio::time::{sleep, Duration};
+
+fn drawn_bar(buf: &SharedBuf) -> Bar {
+    Bar::with_config(10, BarConfig::no_colors().with_target(DrawTarget::terminal_with_width(buf.clone(), 40)))
+}
+
+#[tokio::test]
+async fn test_println_goes_above_bar() {
+    let buf = SharedBuf::default();
+    let bar = drawn_bar(&buf);
+    bar.set_message("working").await;
+    sleep(Duration::from_millis(20)).await;
+
+    bar.println("compiled foo");
+    let output = buf.contents();
+    let (before, after) = output.rsplit_once("compiled foo\n").expect("line was printed");
+    assert!(before.ends_with("\x1b[J"), "bar was not erased first: {output:?}");
+    assert!(after.ends_with("] 0% working"), "bar was not redrawn: {output:?}");
+}
+
+#[tokio::test]
+async fn test_suspend_hides_bar_while_writing() {
+    let buf = SharedBuf::default();
+    let bar = drawn_bar(&buf);
+    bar.set_message("working").await;
+    sleep(Duration::from_millis(20)).await;
+
+    let mut out = buf.clone();
+    let written = bar.suspend(|| {
+        assert!(buf.contents().ends_with("\x1b[J"), "bar still visible inside suspend");
+        out.write_all(b"external output\n").unwrap();
+        42
+    });
+    assert_eq!(written, 42);
+    let output = buf.contents();
+    let (_, after) = output.rsplit_once("external output\n").expect("output was written");
+    assert!(after.ends_with("] 0% working"), "bar was not redrawn: {output:?}");
+}
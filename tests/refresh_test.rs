@@ -0,0 +1,56 @@
+#![cfg(feature = "tokio")]
+
+mod common;
+
+use common::SharedBuf;
+use throbberous::{testing::{self, VirtualTerminal}, Bar, BarConfig, Clock, DrawTarget};
+use tokio::time::Duration;
+
+#[tokio::test]
+async fn test_updates_between_refreshes_are_coalesced() {
+    let clock = Clock::manual();
+    let term = VirtualTerminal::new(60, 3);
+    let config = BarConfig::no_colors().with_target(term.target()).with_clock(clock.clone()).with_max_refresh_rate(10);
+    let bar = Bar::with_config(1000, config);
+    for _ in 0..500 {
+        bar.inc(1).await;
+        testing::settle().await;
+    }
+    term.assert_frames(&["[                                        ] 0% Working..."]);
+
+    clock.advance(Duration::from_millis(100));
+    testing::settle().await;
+    assert_eq!(term.frames().len(), 2);
+    term.assert_screen("[====================                    ] 50% Working...");
+
+    // Finishing does not wait for the next refresh
+    bar.finish_with_message("Done!").await;
+    testing::settle().await;
+    term.assert_screen("[========================================] 100% Done!");
+}
+
+#[tokio::test]
+async fn test_unchanged_line_is_not_redrawn() {
+    let buf = SharedBuf::default();
+    let clock = Clock::manual();
+    let config = BarConfig {
+        max_refresh_rate: None,
+        ..BarConfig::default().with_target(DrawTarget::terminal(buf.clone())).with_clock(clock.clone())
+    };
+    let bar = Bar::with_config(10, config);
+    bar.set_message("steady").await;
+    testing::settle().await;
+    let drawn = buf.contents();
+    assert!(drawn.contains("0% steady"), "unexpected output: {drawn:?}");
+
+    for _ in 0..5 {
+        bar.set_message("steady").await;
+        testing::settle().await;
+    }
+    assert_eq!(buf.contents(), drawn);
+
+    // The colors still cycle on their own
+    clock.advance(Duration::from_millis(600));
+    testing::settle().await;
+    assert_ne!(buf.contents(), drawn);
+}
@@ -0,0 +1,43 @@
+use std::time::Duration;
+use throbberous::{testing::VirtualTerminal, Bar, BarConfig};
+
+#[test]
+fn test_bar_created_outside_any_runtime() {
+    let term = VirtualTerminal::new(60, 3);
+    let bar = Bar::with_config(2, BarConfig::no_colors().with_target(term.target()));
+    tokio_test::block_on(async {
+        bar.inc(1).await;
+        bar.finish_with_message("threaded").await;
+    });
+    std::thread::sleep(Duration::from_millis(100));
+
+    term.assert_screen("[========================================] 100% threaded");
+}
+
+#[cfg(feature = "smol")]
+#[test]
+fn test_bar_on_smol() {
+    smol::block_on(async {
+        let term = VirtualTerminal::new(60, 3);
+        let bar = Bar::with_config(4, BarConfig::no_colors().with_target(term.target()));
+        bar.inc(2).await;
+        throbberous::testing::step(Duration::from_millis(50)).await;
+        term.assert_screen("[====================                    ] 50% Halfway done");
+
+        bar.finish_with_message("smol").await;
+        throbberous::testing::step(Duration::from_millis(50)).await;
+        term.assert_screen("[========================================] 100% smol");
+    });
+}
+
+#[cfg(feature = "async-std")]
+#[test]
+fn test_bar_on_async_std() {
+    async_std::task::block_on(async {
+        let term = VirtualTerminal::new(60, 3);
+        let bar = Bar::with_config(4, BarConfig::no_colors().with_target(term.target()));
+        bar.finish_with_message("async-std").await;
+        throbberous::testing::step(Duration::from_millis(50)).await;
+        term.assert_screen("[========================================] 100% async-std");
+    });
+}
@@ -0,0 +1,40 @@
+use throbberous::{Frames, Spinner, ThrobberConfig};
+use unicode_width::UnicodeWidthStr;
+
+#[test]
+fn test_presets_have_uniform_frames() {
+    for spinner in Spinner::ALL {
+        let frames = spinner.frames();
+        assert!(frames.len() > 1, "{spinner:?} does not animate");
+        assert!(spinner.frame_delay() > 0);
+        for frame in frames {
+            assert_eq!(frame.width(), spinner.width(), "{spinner:?} frame {frame:?} has the wrong width");
+        }
+    }
+    assert_eq!(Spinner::Moon.width(), 2);
+    assert_eq!(Spinner::BouncingBall.width(), 8);
+}
+
+#[test]
+fn test_preset_config() {
+    let config = ThrobberConfig::preset(Spinner::Arc);
+    assert_eq!(config.frames.frame(0), "◜");
+    assert_eq!(config.frames.frame(7), "◠");
+    assert_eq!(config.frame_delay, 100);
+    assert!(config.colors.is_some());
+
+    let default = ThrobberConfig::default();
+    let frames: Vec<_> = (0..4).map(|tick| default.frames.frame(tick).into_owned()).collect();
+    assert_eq!(frames, ["|", "/", "-", "\\"]);
+    assert_eq!(default.frame_delay, 150);
+}
+
+#[test]
+fn test_owned_and_generated_frames() {
+    let owned = Frames::from(vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(owned.frame(3), "b");
+    assert_eq!(Frames::from(Vec::<&'static str>::new()).frame(5), "");
+
+    let generated = Frames::generator(|tick| format!("step {}", tick));
+    assert_eq!(generated.frame(42), "step 42");
+}
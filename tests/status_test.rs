@@ -0,0 +1,62 @@
+#![cfg(feature = "tokio")]
+
+use crossterm::style::Color;
+use throbberous::{testing::{self, VirtualTerminal}, Bar, BarConfig, Mark, Marks, Status, Throbber, ThrobberConfig};
+
+#[tokio::test]
+async fn test_bar_endings_are_marked_and_queryable() {
+    let term = VirtualTerminal::new(80, 5);
+    let config = BarConfig::no_colors().with_target(term.target());
+    let failed = Bar::with_config(10, config.clone());
+    failed.set_position(4);
+    assert_eq!(failed.status(), Status::InProgress);
+    failed.fail("checksum mismatch").await;
+    // Too late to finish, so its message is ignored too
+    failed.finish_with_message("all good").await;
+    testing::settle().await;
+    let skipped = Bar::with_config(10, config.clone());
+    skipped.skip("already cached").await;
+    testing::settle().await;
+    let done = Bar::with_config(2, config.with_marks(Marks { finished: Some(Mark::new("✔", Some(Color::Green))), ..Marks::default() }));
+    done.set_position(2);
+    testing::settle().await;
+
+    term.assert_screen(
+        "✖ [================                        ] 40% checksum mismatch\n\
+         ↷ [                                        ] 0% already cached\n\
+         ✔ [========================================] 100% Complete!",
+    );
+    assert_eq!(failed.status(), Status::Failed);
+    assert_eq!(skipped.status(), Status::Skipped);
+    assert_eq!(done.status(), Status::Finished);
+
+    // The first ending sticks
+    failed.finish().await;
+    assert_eq!(failed.status(), Status::Failed);
+    assert_eq!(failed.position(), 4);
+
+    // A bar seen at its total stays finished, even before it was redrawn
+    let complete = Bar::with_config(3, BarConfig::no_colors().with_target(term.target()));
+    complete.set_position(3);
+    assert_eq!(complete.status(), Status::Finished);
+    complete.fail("too late").await;
+    assert_eq!(complete.status(), Status::Finished);
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_throbber_endings_are_marked_and_queryable() {
+    let term = VirtualTerminal::new(40, 4);
+    let config = ThrobberConfig { frame_delay: 100, ..ThrobberConfig::no_colors().with_target(term.target()) };
+    let throbber = Throbber::with_config(config);
+    throbber.set_message("fetching").await;
+    let spinning = throbber.start().await;
+    testing::step(std::time::Duration::from_millis(100)).await;
+    assert_eq!(throbber.status(), Status::InProgress);
+
+    throbber.fail("connection refused").await;
+    spinning.stop().await;
+    testing::settle().await;
+    term.assert_screen("✖ connection refused");
+    assert_eq!(term.cursor(), (0, 1));
+    assert_eq!(throbber.status(), Status::Failed);
+}
@@ -0,0 +1,48 @@
+mod common;
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, DrawTarget, Template, TemplateError};
+use tokio::time::{sleep, Duration};
+
+#[test]
+fn test_template_parse_errors() {
+    assert_eq!(Template::parse("[{bar"), Err(TemplateError::UnclosedField(1)));
+    assert_eq!(Template::parse("oops }"), Err(TemplateError::UnmatchedBrace(5)));
+    assert_eq!(Template::parse("{nope}"), Err(TemplateError::UnknownField("nope".into())));
+    assert_eq!(Template::parse("{bar:wide}"), Err(TemplateError::InvalidWidth("wide".into())));
+    assert_eq!(Template::parse("{msg:.sparkly}"), Err(TemplateError::UnknownStyle("sparkly".into())));
+    assert!(Template::parse("{{literal}} {prefix:>8.bold.on_blue} {msg:^10.dim}").is_ok());
+}
+
+#[tokio::test]
+async fn test_template_renders_fields() {
+    let buf = SharedBuf::default();
+    let template = Template::parse("{prefix:>6} {bar:10} {pos}/{len} {percent}% {msg}").unwrap();
+    let config = BarConfig::no_colors()
+        .with_target(DrawTarget::terminal(buf.clone()))
+        .with_template(template);
+    let bar = Bar::with_config(4, config);
+    bar.set_prefix("copy").await;
+    bar.set_message("files").await;
+    bar.inc(1).await;
+    sleep(Duration::from_millis(20)).await;
+
+    let output = buf.contents();
+    assert!(output.ends_with("  copy [===       ] 1/4 25% files"), "unexpected output: {output:?}");
+}
+
+#[tokio::test]
+async fn test_padding_counts_terminal_columns() {
+    let buf = SharedBuf::default();
+    let template = Template::parse("[{msg:^6}] [{prefix:>4}]").unwrap();
+    let config = BarConfig::no_colors()
+        .with_target(DrawTarget::terminal(buf.clone()))
+        .with_template(template);
+    let bar = Bar::with_config(4, config);
+    bar.set_prefix("✨").await;
+    bar.set_message("日本").await;
+    sleep(Duration::from_millis(20)).await;
+
+    let output = buf.contents();
+    assert!(output.ends_with("[ 日本 ] [  ✨]"), "unexpected output: {output:?}");
+}
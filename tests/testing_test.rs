@@ -0,0 +1,46 @@
+use std::io::Write;
+
+use throbberous::testing::VirtualTerminal;
+#[cfg(feature = "tokio")]
+use throbberous::{testing, Throbber, ThrobberConfig};
+#[cfg(feature = "tokio")]
+use tokio::time::Duration;
+
+#[test]
+fn test_escape_sequences_redraw_in_place() {
+    let mut term = VirtualTerminal::new(20, 3);
+    write!(term, "first\nsecond\x1b[1A\x1b[1G\x1b[Jredrawn\x1b[38;5;2m!\x1b[0m").unwrap();
+    term.flush().unwrap();
+
+    term.assert_screen("redrawn!");
+    assert_eq!(term.cursor(), (8, 0));
+}
+
+#[test]
+fn test_wrapping_scrolling_and_wide_chars() {
+    let mut term = VirtualTerminal::new(4, 2);
+    write!(term, "abcdef\n🌑🌒").unwrap();
+    term.flush().unwrap();
+
+    assert_eq!(term.scrollback(), ["abcd"]);
+    term.assert_screen("ef\n🌑🌒");
+    write!(term, "x").unwrap();
+    term.flush().unwrap();
+    assert_eq!(term.scrollback(), ["abcd", "ef"]);
+    term.assert_screen("🌑🌒\nx");
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test(start_paused = true)]
+async fn test_throbber_golden_frames() {
+    let term = VirtualTerminal::new(20, 2);
+    let config = ThrobberConfig { frame_delay: 100, ..ThrobberConfig::no_colors().with_target(term.target()) };
+    let throbber = Throbber::with_config(config);
+    throbber.set_message("loading").await;
+    let _spinning = throbber.start().await;
+    for _ in 0..4 {
+        testing::step(Duration::from_millis(100)).await;
+    }
+
+    term.assert_frames(&["/ loading", "- loading", "\\ loading", "| loading"]);
+}
@@ -0,0 +1,34 @@
+mod common;
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, DrawTarget, Template, Unit};
+use tokio::time::{sleep, Duration};
+
+#[test]
+fn test_unit_formatting() {
+    assert_eq!(Unit::Count.format(1234), "1234");
+    assert_eq!(Unit::Bytes.format(512), "512 B");
+    assert_eq!(Unit::Bytes.format(327_571_866), "312.4 MiB");
+    assert_eq!(Unit::Bytes.format(1_288_490_189), "1.2 GiB");
+    assert_eq!(Unit::DecimalBytes.format(1_500_000), "1.5 MB");
+    assert_eq!(Unit::label("files").format(12), "12 files");
+
+    assert_eq!(Unit::Count.format_rate(12.5), "12.5/s");
+    assert_eq!(Unit::Bytes.format_rate(48.0 * 1024.0 * 1024.0), "48.0 MiB/s");
+    assert_eq!(Unit::Bytes.format_rate(0.0), "0.0 B/s");
+    assert_eq!(Unit::label("rows").format_rate(3.44), "3.4 rows/s");
+}
+
+#[tokio::test]
+async fn test_bar_uses_unit_for_pos_and_len() {
+    let buf = SharedBuf::default();
+    let config = BarConfig::no_colors()
+        .with_target(DrawTarget::terminal_with_width(buf.clone(), 80))
+        .with_template(Template::parse("{pos} / {len}").unwrap())
+        .with_unit(Unit::Bytes);
+    let bar = Bar::with_config(2 * 1024 * 1024, config);
+    bar.set_position(1536).await;
+    sleep(Duration::from_millis(20)).await;
+
+    assert_eq!(buf.last_line(), "1.5 KiB / 2.0 MiB");
+}
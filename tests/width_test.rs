@@ -0,0 +1,43 @@
+mod common;
+
+use common::SharedBuf;
+use throbberous::{Bar, BarConfig, BarWidth, DrawTarget};
+use tokio::time::{sleep, Duration};
+
+#[tokio::test]
+async fn test_fill_uses_remaining_columns() {
+    let buf = SharedBuf::default();
+    let config = BarConfig {
+        width: BarWidth::Fill,
+        ..BarConfig::no_colors().with_target(DrawTarget::terminal_with_width(buf.clone(), 30))
+    };
+    let bar = Bar::with_config(10, config);
+    bar.set_message("abc").await;
+    sleep(Duration::from_millis(20)).await;
+
+    // "[" + bar + "] 0% abc" fills exactly 30 columns
+    let line = buf.last_line();
+    assert_eq!(line.chars().count(), 30, "unexpected line: {line:?}");
+    assert!(line.ends_with("] 0% abc"));
+}
+
+#[tokio::test]
+async fn test_long_message_is_truncated() {
+    let buf = SharedBuf::default();
+    let config = BarConfig {
+        width: BarWidth::Fraction(0.5),
+        ..BarConfig::no_colors().with_target(DrawTarget::terminal_with_width(buf.clone(), 20))
+    };
+    let bar = Bar::with_config(10, config);
+    bar.set_message("a message far longer than the terminal").await;
+    sleep(Duration::from_millis(20)).await;
+
+    let line = buf.last_line();
+    assert_eq!(line, "[          ] 0% a me");
+}
+
+#[test]
+fn test_bar_width_from_usize() {
+    assert_eq!(BarWidth::from(25), BarWidth::Fixed(25));
+    assert_eq!(BarWidth::default(), BarWidth::Fixed(40));
+}